@@ -31,6 +31,8 @@ const TAG_DESTROYED: u8 = 3;
 #[derive(Debug)]
 pub struct AsyncTag {
     tag: RawTag,
+    /// whether the last read() succeeded
+    has_read: bool,
     inner: Arc<Inner>,
     _guard: ArcGuard<Inner>,
 }
@@ -177,6 +179,7 @@ impl AsyncTag {
         // }
         Ok(Self {
             tag,
+            has_read: false,
             inner,
             _guard: guard,
         })
@@ -193,7 +196,8 @@ impl AsyncTag {
                 }
                 TAG_FIRST_READ => {
                     let (evt, status) = self.inner.take_event();
-                    if evt == event {
+                    // the event of an operation that completed synchronously might come late, skip it
+                    if evt == event && !self.tag.status().is_pending() {
                         return Status::from(status);
                     }
                 }
@@ -224,12 +228,24 @@ impl AsyncTag {
     }
 
     /// perform read operation.
+    ///
+    /// # Cancellation
+    /// if the future is dropped before completion, the pending operation is aborted by [`RawTag::abort`];
+    /// the tag buffer is undefined until the next successful read.
     #[inline]
     pub async fn read(&mut self) -> Result<()> {
-        self.read_or_write(PLCTAG_EVENT_READ_COMPLETED).await
+        // the buffer is undefined until the read succeeds
+        self.has_read = false;
+        self.read_or_write(PLCTAG_EVENT_READ_COMPLETED).await?;
+        self.has_read = true;
+        Ok(())
     }
 
     /// perform write operation
+    ///
+    /// # Cancellation
+    /// if the future is dropped before completion, the pending operation is aborted by [`RawTag::abort`];
+    /// the value in PLC controller is undefined then.
     #[inline]
     pub async fn write(&mut self) -> Result<()> {
         self.read_or_write(PLCTAG_EVENT_WRITE_COMPLETED).await
@@ -251,6 +267,10 @@ impl AsyncTag {
             }
             _ => unreachable!(),
         }
+        if !guard.pending {
+            // completed synchronously, e.g. system tags
+            return Ok(());
+        }
 
         // pending
         let status = self.recv_event(event).await;
@@ -340,21 +360,99 @@ impl AsyncTag {
 
     /// perform read & returns the value
     #[cfg(feature = "value")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self),
+            fields(
+                tag.id = self.tag.id(),
+                tag.path = self.tag.path(),
+                status = tracing::field::Empty
+            ),
+            err
+        )
+    )]
     #[inline]
     pub async fn read_value<T: Decode>(&mut self, offset: u32) -> Result<T> {
         use plctag_core::ValueExt;
-        self.read().await?;
+        let res = self.read().await;
+        #[cfg(feature = "tracing")]
+        record_status(&res);
+        res?;
         //dbg!("read done", self.tag.status());
         Ok(self.tag.get_value(offset)?)
     }
 
+    /// get the value in mem without any I/O, returns `None` if the tag has never been read by [`AsyncTag::read`],
+    /// or the last read failed or was cancelled
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn try_read_value<T: Decode>(&mut self, offset: u32) -> Result<Option<T>> {
+        if !self.has_read {
+            return Ok(None);
+        }
+        Ok(Some(self.get_value(offset)?))
+    }
+
     /// set the value and write to PLC Controller
     #[cfg(feature = "value")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self, value),
+            fields(
+                tag.id = self.tag.id(),
+                tag.path = self.tag.path(),
+                status = tracing::field::Empty
+            ),
+            err
+        )
+    )]
     #[inline]
     pub async fn write_value<T: Encode + Send>(&mut self, offset: u32, value: T) -> Result<()> {
         use plctag_core::ValueExt;
         self.ready().await?;
         self.tag.set_value(offset, value)?;
+        let res = self.write().await;
+        #[cfg(feature = "tracing")]
+        record_status(&res);
+        res
+    }
+
+    /// get `count` values of `T` from mem, you should call read() before this operation
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn get_values<T: Decode>(&mut self, byte_offset: u32, count: usize) -> Result<Vec<T>> {
+        Ok(self.tag.get_values(byte_offset, count)?)
+    }
+
+    /// set values of `T` in mem, you should call write() later
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn set_values<T: Encode>(&mut self, byte_offset: u32, values: &[T]) -> Result<()> {
+        Ok(self.tag.set_values(byte_offset, values)?)
+    }
+
+    /// perform a single read & returns `count` values of `T`
+    #[cfg(feature = "value")]
+    #[inline]
+    pub async fn read_values<T: Decode>(&mut self, offset: u32, count: usize) -> Result<Vec<T>> {
+        self.read().await?;
+        Ok(self.tag.get_values(offset, count)?)
+    }
+
+    /// set the values and write to PLC Controller in a single write
+    #[cfg(feature = "value")]
+    #[inline]
+    pub async fn write_values<T: Encode + Send + Sync>(
+        &mut self,
+        offset: u32,
+        values: &[T],
+    ) -> Result<()> {
+        self.ready().await?;
+        self.tag.set_values(offset, values)?;
         self.write().await?;
         Ok(())
     }
@@ -393,6 +491,19 @@ impl AsyncTag {
     }
 }
 
+/// record the status of the operation in the current span
+#[cfg(feature = "tracing")]
+#[inline]
+fn record_status(res: &Result<()>) {
+    let status = match res {
+        Ok(_) => Status::Ok,
+        Err(Error::TagError(status)) => *status,
+        Err(Error::Timeout) => Status::Err(plctag_core::ffi::PLCTAG_ERR_TIMEOUT),
+        Err(Error::Other(_)) => return,
+    };
+    tracing::Span::current().record("status", tracing::field::display(status));
+}
+
 struct InflightGuard<'a> {
     tag: &'a RawTag,
     pending: bool,
@@ -402,32 +513,31 @@ impl InflightGuard<'_> {
     #[inline]
     fn read(&mut self) -> Result<()> {
         let status = self.tag.read(0);
-        match status {
-            Status::Pending => {
-                self.pending = true;
-                Ok(())
-            }
-            Status::Err(_) => {
-                self.pending = false;
-                Err(status.into())
-            }
-            _ => unreachable!(),
-        }
+        self.start(status)
     }
 
     #[inline]
     fn write(&mut self) -> Result<()> {
         let status = self.tag.write(0);
+        self.start(status)
+    }
+
+    /// `pending` is cleared if the operation completed synchronously or failed
+    #[inline]
+    fn start(&mut self, status: Status) -> Result<()> {
         match status {
             Status::Pending => {
                 self.pending = true;
                 Ok(())
             }
+            Status::Ok => {
+                self.pending = false;
+                Ok(())
+            }
             Status::Err(_) => {
                 self.pending = false;
                 Err(status.into())
             }
-            _ => unreachable!(),
         }
     }
 }
@@ -440,3 +550,112 @@ impl Drop for InflightGuard<'_> {
         }
     }
 }
+
+#[cfg(all(test, feature = "value"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_values() -> anyhow::Result<()> {
+        let mut tag = AsyncTag::create("make=system&family=library&name=debug&debug=4").await?;
+        tag.ready().await?;
+
+        // the first u32 is the global debug level, read it first so that the write keeps it;
+        // the rest of the buffer is local to the tag
+        tag.read().await?;
+        tag.write_values(20, &[1u16, 2]).await?;
+        let values: Vec<u16> = tag.read_values(20, 2).await?;
+        assert_eq!(values, [1, 2]);
+
+        let res = tag.read_values::<u32>(28, 1).await;
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_read_value() -> anyhow::Result<()> {
+        let mut tag = AsyncTag::create("make=system&family=library&name=debug&debug=4").await?;
+        tag.ready().await?;
+
+        let value: Option<u32> = tag.try_read_value(0)?;
+        assert!(value.is_none());
+
+        tag.read().await?;
+        let value: Option<u32> = tag.try_read_value(0)?;
+        assert!(value.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_read() -> anyhow::Result<()> {
+        let mut tag = AsyncTag::create("make=system&family=library&name=debug&debug=4").await?;
+        tag.ready().await?;
+
+        // start a read, then drop it before completion
+        {
+            let fut = tag.read();
+            futures_util::pin_mut!(fut);
+            let _ = futures_util::poll!(fut);
+        }
+        assert!(!tag.status().is_pending());
+
+        // the tag is still usable
+        tag.read().await?;
+        let value: Option<u32> = tag.try_read_value(0)?;
+        assert!(value.is_some());
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_tracing_span() -> anyhow::Result<()> {
+        use std::{fmt::Debug, sync::Mutex};
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Metadata, Subscriber,
+        };
+
+        /// records names and fields of spans
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        struct Fields<'a>(&'a mut Vec<String>);
+
+        impl Visit for Fields<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+                let mut names = self.0.lock().unwrap();
+                names.push(span.metadata().name().to_owned());
+                span.record(&mut Fields(&mut names));
+                span::Id::from_u64(names.len() as u64)
+            }
+            fn record(&self, _: &span::Id, values: &span::Record<'_>) {
+                values.record(&mut Fields(&mut self.0.lock().unwrap()));
+            }
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let names = Arc::new(Mutex::new(vec![]));
+        let _guard = tracing::subscriber::set_default(Recorder(names.clone()));
+
+        let mut tag = AsyncTag::create("make=system&family=library&name=debug&debug=4").await?;
+        let _: u32 = tag.read_value(0).await?;
+        let names = names.lock().unwrap();
+        assert!(names.iter().any(|v| v == "read_value"));
+        assert!(names
+            .iter()
+            .any(|v| v == r#"tag.path="make=system&family=library&name=debug&debug=4""#));
+        assert!(names.iter().any(|v| v == "status=PLCTAG_STATUS_OK"));
+        Ok(())
+    }
+}
@@ -55,6 +55,15 @@ mod entry;
 
 pub use entry::AsyncTag;
 
+/// commonly used types and traits, `use plctag_async::prelude::*;`
+pub mod prelude {
+    /// async tag
+    pub use crate::AsyncTag;
+    /// traits to decode/encode values of tag
+    #[cfg(feature = "value")]
+    pub use plctag_core::{Decode, Encode};
+}
+
 use plctag_core::{RawTag, Status};
 use std::{fmt, sync::Arc};
 
@@ -64,6 +73,8 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// errors for [`plctag-async`]
 #[derive(Debug)]
 pub enum Error {
+    /// operation timed out, from status `PLCTAG_ERR_TIMEOUT`
+    Timeout,
     /// plc tag error
     TagError(Status),
     /// other error
@@ -73,7 +84,7 @@ pub enum Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Error::TagError(_) => None,
+            Error::Timeout | Error::TagError(_) => None,
             Error::Other(e) => Some(e.as_ref()),
         }
     }
@@ -82,6 +93,7 @@ impl std::error::Error for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Error::Timeout => write!(f, "Timeout"),
             Error::TagError(e) => write!(f, "TagError - {}", e),
             Error::Other(e) => write!(f, "{}", e),
         }
@@ -90,6 +102,28 @@ impl fmt::Display for Error {
 
 impl From<Status> for Error {
     fn from(s: Status) -> Self {
-        Error::TagError(s)
+        if s.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::TagError(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use plctag_core::ffi;
+
+    #[test]
+    fn test_error_from_status() {
+        let err = Error::from(Status::Err(ffi::PLCTAG_ERR_TIMEOUT));
+        assert!(matches!(err, Error::Timeout));
+
+        let err = Error::from(Status::Err(ffi::PLCTAG_ERR_BAD_GATEWAY));
+        assert!(matches!(
+            err,
+            Error::TagError(Status::Err(ffi::PLCTAG_ERR_BAD_GATEWAY))
+        ));
     }
 }
@@ -9,6 +9,8 @@
 pub use crate::debug::DebugLevel;
 use core::fmt;
 use core::fmt::Write;
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -64,22 +66,76 @@ pub struct PathBuilder {
     debug: Option<DebugLevel>,
     elem_count: Option<usize>,
     elem_size: Option<usize>,
+    /// element size set by `element_type()`, to check the tag size on creation
+    elem_type_size: Option<usize>,
     read_cache_ms: Option<usize>,
     auto_sync_read_ms: Option<usize>,
     auto_sync_write_ms: Option<usize>,
     plc: Option<PlcKind>,
     name: Option<String>,
+    /// whether the name is set by `data_file()`
+    data_file: bool,
     path: Option<String>,
+    route: Vec<String>,
     gateway: Option<String>,
     use_connected_msg: Option<bool>,
     allow_packing: Option<bool>,
     connection_group_id: Option<u16>,
+    extra: Vec<(String, String)>,
 }
 
 impl PathBuilder {
+    /// parse a full tag path into [`PathBuilder`], so that attributes can be changed before building it again.
+    /// attributes that the builder does not know are kept, and appended as they are by [`PathBuilder::build`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use plctag_core::builder::*;
+    ///
+    /// let path = PathBuilder::parse("protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyTag1")
+    ///     .unwrap()
+    ///     .gateway("192.168.1.121")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(path, "protocol=ab-eip&plc=controllogix&gateway=192.168.1.121&path=1,0&name=MyTag1");
+    /// ```
+    pub fn parse(path: &str) -> Result<Self> {
+        let mut builder = Self::default();
+        for item in path.split('&').filter(|v| !v.is_empty()) {
+            let (key, value) = item
+                .split_once('=')
+                .ok_or(Error("attribute must be key=value"))?;
+            let value = value.trim();
+            match key.trim() {
+                "protocol" => builder.protocol = Some(value.parse()?),
+                "plc" | "cpu" => builder.plc = Some(value.parse()?),
+                "gateway" => builder.gateway = Some(value.to_owned()),
+                "name" => builder.name = Some(value.to_owned()),
+                "path" => builder.path = Some(value.to_owned()),
+                "elem_count" => builder.elem_count = Some(parse_attr(value)?),
+                "elem_size" => builder.elem_size = Some(parse_attr(value)?),
+                "read_cache_ms" => builder.read_cache_ms = Some(parse_attr(value)?),
+                "auto_sync_read_ms" => builder.auto_sync_read_ms = Some(parse_attr(value)?),
+                "auto_sync_write_ms" => builder.auto_sync_write_ms = Some(parse_attr(value)?),
+                "connection_group_id" => builder.connection_group_id = Some(parse_attr(value)?),
+                "use_connected_msg" => builder.use_connected_msg = Some(parse_flag(value)?),
+                "allow_packing" => builder.allow_packing = Some(parse_flag(value)?),
+                "debug" => {
+                    let level: u8 = parse_attr(value)?;
+                    if level > DebugLevel::Spew as u8 {
+                        return Err(Error("debug level is a number in range [0-5]"));
+                    }
+                    builder.debug = Some(level.into());
+                }
+                key => builder.extra.push((key.to_owned(), value.to_owned())),
+            }
+        }
+        Ok(builder)
+    }
+
     /// generic attribute.
     /// defining the current debugging level.
-    /// please use [`plc::set_debug_level`](../plc/fn.set_debug_level.html) instead.
+    /// please use [`set_debug_level`](crate::set_debug_level) instead.
     #[deprecated]
     pub fn debug(&mut self, level: DebugLevel) -> &mut Self {
         self.debug = Some(level);
@@ -104,9 +160,28 @@ impl PathBuilder {
     /// Required for some protocols or PLC types. This attribute determines the size of a single element of the tag. All tags are considered to be arrays, even those with only one entry. Ignored for Modbus and for ControlLogix-class Allen-Bradley PLCs. This parameter will become optional for as many PLC types as possible
     pub fn element_size(&mut self, size: usize) -> &mut Self {
         self.elem_size = Some(size);
+        self.elem_type_size = None;
         self
     }
 
+    /// generic attribute.
+    /// shortcut for `element_size(size_of::<T>())` and `element_count(count)`, e.g. `element_type::<i32>(16)` for a DINT[16].
+    ///
+    /// [`RawTag::from_builder`](crate::RawTag::from_builder) checks that the tag size reported by the PLC
+    /// equals `size_of::<T>() * count` once the tag is created.
+    pub fn element_type<T>(&mut self, count: usize) -> &mut Self {
+        self.element_size(std::mem::size_of::<T>())
+            .element_count(count);
+        self.elem_type_size = Some(std::mem::size_of::<T>());
+        self
+    }
+
+    /// tag size in bytes expected by `element_type()`
+    pub(crate) fn expected_size(&self) -> Option<usize> {
+        let size = self.elem_type_size?;
+        size.checked_mul(self.elem_count.unwrap_or(1))
+    }
+
     /// generic attribute:
     /// Optional. An integer number of milliseconds to cache read data.
     /// Use this attribute to cause the tag read operations to cache data the requested number of milliseconds. This can be used to lower the actual number of requests against the PLC. Example read_cache_ms=100 will result in read operations no more often than once every 100 milliseconds.
@@ -115,13 +190,15 @@ impl PathBuilder {
         self
     }
 
-    /// Optional An integer number of milliseconds to periodically read data from the PLC
+    /// Optional An integer number of milliseconds to periodically read data from the PLC.
+    /// see also [`RawTag::set_auto_sync_read_ms`](crate::RawTag::set_auto_sync_read_ms) to change it at runtime
     pub fn auto_sync_read_ms(&mut self, millis: usize) -> &mut Self {
         self.auto_sync_read_ms = Some(millis);
         self
     }
 
-    /// Optional An integer number of milliseconds to buffer tag data changes before writing to the PLC
+    /// Optional An integer number of milliseconds to buffer tag data changes before writing to the PLC.
+    /// see also [`RawTag::set_auto_sync_write_ms`](crate::RawTag::set_auto_sync_write_ms) to change it at runtime
     pub fn auto_sync_write_ms(&mut self, millis: usize) -> &mut Self {
         self.auto_sync_write_ms = Some(millis);
         self
@@ -133,12 +210,35 @@ impl PathBuilder {
         self
     }
 
+    /// EIP only
+    /// shortcut for `protocol(Protocol::EIP)` and `plc(PlcKind::Omron)`, for Omron NJ/NX-class PLCs.
+    /// a path is required, usually `18,<ip of the PLC>`.
+    pub fn omron_njnx(&mut self) -> &mut Self {
+        self.protocol(Protocol::EIP).plc(PlcKind::Omron)
+    }
+
+    /// EIP only
+    /// shortcut for `protocol(Protocol::EIP)` and `plc(PlcKind::Micro800)`.
+    /// Micro800 connections must not have a path, building fails if a path or route segments are set.
+    pub fn micro800(&mut self) -> &mut Self {
+        self.protocol(Protocol::EIP).plc(PlcKind::Micro800)
+    }
+
+    /// ModBus only
+    /// shortcut for `protocol(Protocol::ModBus)` and `path(unit_id)`, use `register()` for the name.
+    pub fn modbus(&mut self, unit_id: u8) -> &mut Self {
+        self.protocol(Protocol::ModBus).path(unit_id.to_string())
+    }
+
     /// - EIP
     /// IP address or host name.
     /// This tells the library what host name or IP address to use for the PLC or the gateway to the PLC (in the case that the PLC is remote).
     /// - ModBus
     /// Required IP address or host name and optional port
     /// This tells the library what host name or IP address to use for the PLC. Can have an optional port at the end, e.g. gateway=10.1.2.3:502 where the :502 part specifies the port.
+    ///
+    /// IPv6 address should be enclosed in brackets if port provided, e.g. `[fe80::1]:44818`. The gateway is validated by `build()`,
+    /// and passed to `libplctag` as is; IPv6 connectivity depends on the version of `libplctag` linked.
     pub fn gateway(&mut self, gateway: impl AsRef<str>) -> &mut Self {
         self.gateway = Some(gateway.as_ref().to_owned());
         self
@@ -153,13 +253,33 @@ impl PathBuilder {
     /// you might want to use `register()` instead of `name()` for Modbus
     pub fn name(&mut self, name: impl AsRef<str>) -> &mut Self {
         self.name = Some(name.as_ref().to_owned());
+        self.data_file = false;
+        self
+    }
+
+    /// set name of an array element for EIP, e.g. `MyArray[5]`
+    pub fn name_array(&mut self, base: impl AsRef<str>, index: usize) -> &mut Self {
+        self.name(format!("{}[{}]", base.as_ref(), index))
+    }
+
+    /// set name of a member for EIP, e.g. `MyUDT.Member` or `Program:Main.Data`
+    pub fn name_member(&mut self, base: impl AsRef<str>, member: impl AsRef<str>) -> &mut Self {
+        self.name(format!("{}.{}", base.as_ref(), member.as_ref()))
+    }
+
+    /// set data-file address as name for PLC/5, SLC 500 and MicroLogix,
+    /// e.g. `N7:0`, `F8:2`, `B3:0/5`, `B3/5` or `T4:0.ACC`.
+    /// The address is validated by `build()` whatever the PLC kind; a name set by `name()` is validated
+    /// as a data-file address only if the PLC kind is PLC/5, SLC 500, MicroLogix or Logix PCCC.
+    pub fn data_file(&mut self, file: impl AsRef<str>) -> &mut Self {
+        self.name(file);
+        self.data_file = true;
         self
     }
 
     /// set register for Modbus
     pub fn register(&mut self, reg: Register) -> &mut Self {
-        self.name = Some(format!("{}", reg));
-        self
+        self.name(format!("{}", reg))
     }
 
     /// - EIP
@@ -173,9 +293,26 @@ impl PathBuilder {
         self
     }
 
+    /// EIP only
+    /// append a segment of CIP routing path, e.g. port or slot number, IP address of remote module.
+    /// the segments are joined with commas as the `path` attribute.
+    pub fn add_route_segment(&mut self, segment: impl Into<String>) -> &mut Self {
+        self.route.push(segment.into());
+        self
+    }
+
+    /// EIP only
+    /// CIP routing path by segments, replaces previously added segments, see [`PathBuilder::add_route_segment`]
+    pub fn routing(&mut self, segments: &[&str]) -> &mut Self {
+        self.route = segments.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
     /// EIP only
     /// Optional 1 = use CIP connection, 0 = use UCMM.
     /// Control whether to use connected or unconnected messaging. Only valid on Logix-class PLCs. Connected messaging is required on Micro800 and DH+ bridged links. Default is PLC-specific and link-type specific. Generally you do not need to set this.
+    ///
+    /// The attribute is emitted only if set, otherwise `libplctag` picks the default.
     pub fn use_connected_msg(&mut self, yes: bool) -> &mut Self {
         self.use_connected_msg = Some(yes);
         self
@@ -201,21 +338,49 @@ impl PathBuilder {
             None => return Err(Error("protocol required")),
             Some(v) => v,
         };
+        if self.route.iter().any(|v| v.trim().is_empty()) {
+            return Err(Error("route segment must not be empty"));
+        }
+        if self.path.is_some() && !self.route.is_empty() {
+            return Err(Error("path conflicts with route segments"));
+        }
+        if let Some(ref gateway) = self.gateway {
+            check_gateway(gateway)?;
+        }
+        if let Some(ref name) = self.name {
+            if name.is_empty() {
+                return Err(Error("tag name must not be empty"));
+            }
+            // i.e. an empty base of `name_array()` or `name_member()`
+            if name.starts_with(['[', '.']) {
+                return Err(Error("tag name must not start with `[` or `.`"));
+            }
+            if name.ends_with('.') || name.contains("..") {
+                return Err(Error("tag member name must not be empty"));
+            }
+            if self.data_file || matches!(self.plc, Some(plc) if plc.is_pccc()) {
+                check_data_file(name)?;
+            }
+        }
         // check required attributes
         match protocol {
             Protocol::EIP => {
-                //TODO: check gateway, either ip or host name
                 //check plc, required
                 if self.plc.is_none() {
                     return Err(Error("plc kind required"));
                 }
                 let plc = self.plc.unwrap();
                 if plc == PlcKind::ControlLogix {
-                    if self.path.is_none() {
+                    if !self.has_path() {
                         return Err(Error("path required for controllogix"));
                     }
                     return Ok(()); //skip check for elem_size
-                } else if plc == PlcKind::Micro800 && self.path.is_some() {
+                } else if plc == PlcKind::Omron {
+                    if !self.has_path() {
+                        return Err(Error("path required for omron"));
+                    }
+                    return Ok(()); //skip check for elem_size
+                } else if plc == PlcKind::Micro800 && self.has_path() {
                     return Err(Error("path must not provided for micro800"));
                 }
                 if self.elem_size.is_none() {
@@ -223,13 +388,21 @@ impl PathBuilder {
                 }
             }
             Protocol::ModBus => {
-                //TODO: check gateway, host with port
                 if self.gateway.is_none() {
                     return Err(Error("gateway required"));
                 }
                 if self.name.is_none() {
                     return Err(Error("name required"));
                 }
+                if !self.route.is_empty() {
+                    return Err(Error("route segments not supported for modbus"));
+                }
+                if self.plc.is_some()
+                    || self.use_connected_msg.is_some()
+                    || self.allow_packing.is_some()
+                {
+                    return Err(Error("EIP only attributes not supported for modbus"));
+                }
                 //path is number [0-255]
                 match self.path {
                     Some(ref path) => {
@@ -247,6 +420,11 @@ impl PathBuilder {
         Ok(())
     }
 
+    #[inline]
+    fn has_path(&self) -> bool {
+        self.path.is_some() || !self.route.is_empty()
+    }
+
     /// build full tag path
     pub fn build(&self) -> Result<String> {
         self.check()?;
@@ -276,6 +454,8 @@ impl PathBuilder {
         }
         if let Some(ref path) = self.path {
             write!(path_buf, "&path={}", path)?;
+        } else if !self.route.is_empty() {
+            write!(path_buf, "&path={}", self.route.join(","))?;
         }
         if let Some(ref name) = self.name {
             write!(path_buf, "&name={}", name)?;
@@ -308,10 +488,120 @@ impl PathBuilder {
             let level = debug as u8;
             write!(path_buf, "&debug={}", level)?;
         }
+
+        for (key, value) in self.extra.iter() {
+            write!(path_buf, "&{}={}", key, value)?;
+        }
         Ok(path_buf)
     }
 }
 
+#[inline]
+fn parse_attr<T: FromStr>(value: &str) -> Result<T> {
+    value.parse().or(Err(Error("invalid number")))
+}
+
+/// gateway is an IPv4 address, a bracketed IPv6 address or a host name, with an optional port
+fn check_gateway(gateway: &str) -> Result<()> {
+    const INVALID: Error =
+        Error("gateway must be ip or host name, with optional port in range [1-65535]");
+    // bare IPv6 address without port
+    if gateway.parse::<Ipv6Addr>().is_ok() {
+        return Ok(());
+    }
+    let check_port = |port: &str| match port.parse::<u16>() {
+        Ok(v) if v > 0 => Ok(()),
+        _ => Err(INVALID),
+    };
+    if let Some(rest) = gateway.strip_prefix('[') {
+        let (ip, port) = rest.split_once(']').ok_or(INVALID)?;
+        ip.parse::<Ipv6Addr>().or(Err(INVALID))?;
+        return match port {
+            "" => Ok(()),
+            _ => check_port(port.strip_prefix(':').ok_or(INVALID)?),
+        };
+    }
+    let host = match gateway.split_once(':') {
+        Some((host, port)) => {
+            check_port(port)?;
+            host
+        }
+        None => gateway,
+    };
+    if host.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        host.parse::<Ipv4Addr>().or(Err(INVALID))?;
+        return Ok(());
+    }
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+    if host.len() > 253 || !host.split('.').all(valid_label) {
+        return Err(INVALID);
+    }
+    Ok(())
+}
+
+/// data-file address: file type letters, optional file number, `:` element,
+/// then optional `.` sub-element and `/` bit, e.g. `N7:0`, `S:1/5`, `T4:0.ACC`;
+/// or a bit of a bit file: file type letters, file number, `/` bit, e.g. `B3/5`
+fn check_data_file(name: &str) -> Result<()> {
+    const INVALID: Error = Error("invalid data-file address, e.g. N7:0");
+    let is_number = |v: &str| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit());
+    let is_file_type =
+        |v: &str| !v.is_empty() && v.len() <= 3 && v.chars().all(|c| c.is_ascii_alphabetic());
+    let (file, rest) = match name.split_once(':') {
+        Some(v) => v,
+        None => {
+            let (file, bit) = name.split_once('/').ok_or(INVALID)?;
+            let letters = file.trim_end_matches(|c: char| c.is_ascii_digit());
+            if !is_file_type(letters) || letters.len() == file.len() || !is_number(bit) {
+                return Err(INVALID);
+            }
+            return Ok(());
+        }
+    };
+    let letters = file.trim_end_matches(|c: char| c.is_ascii_digit());
+    if !is_file_type(letters) {
+        return Err(INVALID);
+    }
+    let (rest, bit) = match rest.split_once('/') {
+        Some((rest, bit)) => (rest, Some(bit)),
+        None => (rest, None),
+    };
+    let (element, sub) = match rest.split_once('.') {
+        Some((element, sub)) => (element, Some(sub)),
+        None => (rest, None),
+    };
+    if !is_number(element) {
+        return Err(INVALID);
+    }
+    if let Some(sub) = sub {
+        if !is_number(sub) && (sub.is_empty() || !sub.chars().all(|c| c.is_ascii_alphabetic())) {
+            return Err(INVALID);
+        }
+    }
+    if let Some(bit) = bit {
+        if !is_number(bit) {
+            return Err(INVALID);
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+fn parse_flag(value: &str) -> Result<bool> {
+    match value {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(Error("flag must be 0 or 1")),
+    }
+}
+
 /// library supported protocols
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Protocol {
@@ -321,6 +611,17 @@ pub enum Protocol {
     ModBus,
 }
 
+impl FromStr for Protocol {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ab-eip" | "ab_eip" => Ok(Protocol::EIP),
+            "modbus-tcp" | "modbus_tcp" => Ok(Protocol::ModBus),
+            _ => Err(Error("unknown protocol")),
+        }
+    }
+}
+
 impl fmt::Display for Protocol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -372,6 +673,36 @@ pub enum PlcKind {
     Omron,
 }
 
+impl FromStr for PlcKind {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        // same aliases as `libplctag`
+        match s.to_lowercase().as_str() {
+            "controllogix" | "contrologix" | "compactlogix" | "clgx" | "lgx" | "logix"
+            | "flexlogix" | "flgx" => Ok(PlcKind::ControlLogix),
+            "plc5" | "plc" => Ok(PlcKind::PLC5),
+            "slc500" | "slc" => Ok(PlcKind::SLC500),
+            "logixpccc" | "lgxpccc" | "logix-pccc" | "lgx-pccc" | "logixplc5" | "lgxplc5"
+            | "logix-plc5" | "lgx-plc5" => Ok(PlcKind::LogixPCCC),
+            "micro800" | "micro-800" | "micrologix800" | "mlgx800" => Ok(PlcKind::Micro800),
+            "micrologix" | "mlgx" => Ok(PlcKind::MicroLogix),
+            "omron-njnx" | "omron-nj" | "omron-nx" | "njnx" | "nx1p2" => Ok(PlcKind::Omron),
+            _ => Err(Error("unknown plc kind")),
+        }
+    }
+}
+
+impl PlcKind {
+    /// PLCs addressed by data files over PCCC
+    #[inline]
+    fn is_pccc(&self) -> bool {
+        matches!(
+            self,
+            PlcKind::PLC5 | PlcKind::SLC500 | PlcKind::LogixPCCC | PlcKind::MicroLogix
+        )
+    }
+}
+
 impl fmt::Display for PlcKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -420,4 +751,396 @@ mod tests {
             .unwrap();
         assert_eq!(path, "protocol=modbus-tcp&gateway=192.168.1.120:502&path=0&name=co42&elem_count=1&elem_size=16&read_cache_ms=0");
     }
+
+    #[test]
+    fn test_omron_builder() {
+        let path = PathBuilder::default()
+            .omron_njnx()
+            .gateway("192.168.1.120")
+            .path("18,192.168.1.120")
+            .name("MyTag1")
+            .element_count(1)
+            .build()
+            .unwrap();
+        assert_eq!(path, "protocol=ab-eip&plc=omron-njnx&gateway=192.168.1.120&path=18,192.168.1.120&name=MyTag1&elem_count=1");
+
+        let res = PathBuilder::default()
+            .omron_njnx()
+            .gateway("192.168.1.120")
+            .name("MyTag1")
+            .build();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_element_type() {
+        fn build(builder: &mut PathBuilder) -> String {
+            builder
+                .protocol(Protocol::EIP)
+                .gateway("192.168.1.120")
+                .plc(PlcKind::MicroLogix)
+                .name("N7:0")
+                .build()
+                .unwrap()
+        }
+
+        let path = build(PathBuilder::default().element_type::<i32>(16));
+        assert!(path.ends_with("&elem_count=16&elem_size=4"));
+        let path = build(PathBuilder::default().element_type::<i16>(1));
+        assert!(path.ends_with("&elem_count=1&elem_size=2"));
+        let path = build(PathBuilder::default().element_type::<f64>(3));
+        assert!(path.ends_with("&elem_count=3&elem_size=8"));
+
+        let mut builder = PathBuilder::default();
+        assert_eq!(builder.expected_size(), None);
+        builder.element_type::<i32>(16);
+        assert_eq!(builder.expected_size(), Some(64));
+        builder.element_count(2);
+        assert_eq!(builder.expected_size(), Some(8));
+        builder.element_size(4);
+        assert_eq!(builder.expected_size(), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let path = "protocol=ab-eip&plc=controllogix&gateway=192.168.1.120&path=1,0&name=MyTag1&elem_count=1&elem_size=16&read_cache_ms=0&connection_group_id=10";
+        let builder = PathBuilder::parse(path).unwrap();
+        assert_eq!(builder.build().unwrap(), path);
+
+        let path = PathBuilder::parse(
+            "protocol=ab_eip&cpu=lgx&gateway=192.168.1.120&path=1,0&name=MyTag1&int_attr=1",
+        )
+        .unwrap()
+        .gateway("192.168.1.121")
+        .build()
+        .unwrap();
+        assert_eq!(path, "protocol=ab-eip&plc=controllogix&gateway=192.168.1.121&path=1,0&name=MyTag1&int_attr=1");
+
+        assert!(PathBuilder::parse("protocol=ab-eip&plc=contrologics").is_err());
+        assert!(PathBuilder::parse("protocol=ab-eip&elem_count=x").is_err());
+        assert!(PathBuilder::parse("protocol=ab-eip&debug=9").is_err());
+        assert!(PathBuilder::parse("protocol").is_err());
+    }
+
+    #[test]
+    fn test_plc_kind_display() {
+        assert_eq!(PlcKind::ControlLogix.to_string(), "controllogix");
+        assert_eq!(PlcKind::PLC5.to_string(), "plc5");
+        assert_eq!(PlcKind::SLC500.to_string(), "slc500");
+        assert_eq!(PlcKind::LogixPCCC.to_string(), "logixpccc");
+        assert_eq!(PlcKind::Micro800.to_string(), "micro800");
+        assert_eq!(PlcKind::MicroLogix.to_string(), "micrologix");
+        assert_eq!(PlcKind::Omron.to_string(), "omron-njnx");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let kinds = [
+            PlcKind::ControlLogix,
+            PlcKind::PLC5,
+            PlcKind::SLC500,
+            PlcKind::LogixPCCC,
+            PlcKind::Micro800,
+            PlcKind::MicroLogix,
+            PlcKind::Omron,
+        ];
+        for kind in kinds {
+            assert_eq!(kind.to_string().parse::<PlcKind>().unwrap(), kind);
+            assert_eq!(
+                kind.to_string().to_uppercase().parse::<PlcKind>().unwrap(),
+                kind
+            );
+        }
+        let aliases = [
+            ("CompactLogix", PlcKind::ControlLogix),
+            ("flexlogix", PlcKind::ControlLogix),
+            ("flgx", PlcKind::ControlLogix),
+            ("plc", PlcKind::PLC5),
+            ("slc", PlcKind::SLC500),
+            ("lgx-pccc", PlcKind::LogixPCCC),
+            ("logixplc5", PlcKind::LogixPCCC),
+            ("lgx-plc5", PlcKind::LogixPCCC),
+            ("micrologix800", PlcKind::Micro800),
+            ("mlgx800", PlcKind::Micro800),
+            ("mlgx", PlcKind::MicroLogix),
+            ("nx1p2", PlcKind::Omron),
+        ];
+        for (alias, kind) in aliases {
+            assert_eq!(alias.parse::<PlcKind>().unwrap(), kind);
+            let path = format!("protocol=ab-eip&plc={}&gateway=192.168.1.120", alias);
+            let builder = PathBuilder::parse(&path).unwrap();
+            assert_eq!(builder.plc, Some(kind));
+        }
+        assert!("s7".parse::<PlcKind>().is_err());
+
+        for protocol in [Protocol::EIP, Protocol::ModBus] {
+            assert_eq!(protocol.to_string().parse::<Protocol>().unwrap(), protocol);
+        }
+        assert_eq!("AB_EIP".parse::<Protocol>().unwrap(), Protocol::EIP);
+        assert!("profinet".parse::<Protocol>().is_err());
+    }
+
+    #[test]
+    fn test_micro800_builder() {
+        let path = PathBuilder::default()
+            .micro800()
+            .gateway("192.168.1.120")
+            .name("MyTag1")
+            .element_size(4)
+            .build()
+            .unwrap();
+        assert_eq!(
+            path,
+            "protocol=ab-eip&plc=micro800&gateway=192.168.1.120&name=MyTag1&elem_size=4"
+        );
+        assert!(!path.contains("path="));
+
+        let res = PathBuilder::default()
+            .micro800()
+            .gateway("192.168.1.120")
+            .name("MyTag1")
+            .element_size(4)
+            .add_route_segment("1")
+            .build();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_modbus_shortcut() {
+        let path = PathBuilder::default()
+            .modbus(1)
+            .gateway("192.168.1.120:502")
+            .register(Register::Holding(100))
+            .element_size(16)
+            .element_count(2)
+            .build()
+            .unwrap();
+        assert_eq!(path, "protocol=modbus-tcp&gateway=192.168.1.120:502&path=1&name=hr100&elem_count=2&elem_size=16");
+
+        let res = PathBuilder::default()
+            .modbus(1)
+            .gateway("192.168.1.120:502")
+            .register(Register::Holding(100))
+            .element_size(16)
+            .plc(PlcKind::ControlLogix)
+            .build();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_eip_routing() {
+        let path = PathBuilder::default()
+            .protocol(Protocol::EIP)
+            .gateway("192.168.1.120")
+            .plc(PlcKind::ControlLogix)
+            .name("MyTag1")
+            .add_route_segment("1")
+            .add_route_segment("2")
+            .add_route_segment("2")
+            .add_route_segment("192.168.1.5")
+            .add_route_segment("1")
+            .add_route_segment("0")
+            .build()
+            .unwrap();
+        assert_eq!(path, "protocol=ab-eip&plc=controllogix&gateway=192.168.1.120&path=1,2,2,192.168.1.5,1,0&name=MyTag1");
+
+        let path = PathBuilder::default()
+            .protocol(Protocol::EIP)
+            .gateway("192.168.1.120")
+            .plc(PlcKind::ControlLogix)
+            .name("MyTag1")
+            .routing(&["1", "2", "2", "192.168.1.5", "1", "0"])
+            .build()
+            .unwrap();
+        assert_eq!(path, "protocol=ab-eip&plc=controllogix&gateway=192.168.1.120&path=1,2,2,192.168.1.5,1,0&name=MyTag1");
+
+        let res = PathBuilder::default()
+            .protocol(Protocol::EIP)
+            .gateway("192.168.1.120")
+            .plc(PlcKind::ControlLogix)
+            .name("MyTag1")
+            .routing(&["1", "", "0"])
+            .build();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_name_helpers() {
+        let build = |builder: &mut PathBuilder| {
+            builder
+                .protocol(Protocol::EIP)
+                .gateway("192.168.1.120")
+                .plc(PlcKind::ControlLogix)
+                .path("1,0")
+                .build()
+        };
+
+        let path = build(PathBuilder::default().name_array("MyArray", 5)).unwrap();
+        assert!(path.ends_with("&name=MyArray[5]"));
+
+        let path = build(PathBuilder::default().name_array("MyUDT.Items", 2)).unwrap();
+        assert!(path.ends_with("&name=MyUDT.Items[2]"));
+
+        let path = build(PathBuilder::default().name_member("Program:Main", "Data")).unwrap();
+        assert!(path.ends_with("&name=Program:Main.Data"));
+
+        let res = build(PathBuilder::default().name(""));
+        assert_eq!(res.unwrap_err().to_string(), "tag name must not be empty");
+        let res = build(PathBuilder::default().name_array("", 1));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "tag name must not start with `[` or `.`"
+        );
+        let res = build(PathBuilder::default().name_member("", "Data"));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "tag name must not start with `[` or `.`"
+        );
+        let res = build(PathBuilder::default().name_member("MyUDT", ""));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "tag member name must not be empty"
+        );
+        let res = build(PathBuilder::default().name_member("MyUDT.", "Data"));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_data_file() {
+        let build = |plc: PlcKind, file: &str| {
+            PathBuilder::default()
+                .protocol(Protocol::EIP)
+                .gateway("192.168.1.120")
+                .plc(plc)
+                .data_file(file)
+                .element_size(2)
+                .build()
+        };
+
+        let path = build(PlcKind::PLC5, "N7:0").unwrap();
+        assert_eq!(
+            path,
+            "protocol=ab-eip&plc=plc5&gateway=192.168.1.120&name=N7:0&elem_size=2"
+        );
+        assert!(build(PlcKind::SLC500, "F8:2").is_ok());
+        assert!(build(PlcKind::MicroLogix, "B3:0/5").is_ok());
+        assert!(build(PlcKind::SLC500, "T4:0.ACC").is_ok());
+        assert!(build(PlcKind::SLC500, "S:1/5").is_ok());
+        assert!(build(PlcKind::SLC500, "B3/5").is_ok());
+
+        assert!(build(PlcKind::PLC5, "X:abc").is_err());
+        assert!(build(PlcKind::PLC5, "N7").is_err());
+        assert!(build(PlcKind::PLC5, "7:0").is_err());
+        assert!(build(PlcKind::PLC5, "N7:0/").is_err());
+        assert!(build(PlcKind::PLC5, "B/5").is_err());
+        assert!(build(PlcKind::PLC5, "B3/").is_err());
+
+        // validated whatever the plc kind
+        assert!(build(PlcKind::Micro800, "N7:0").is_ok());
+        assert!(build(PlcKind::Micro800, "X:abc").is_err());
+        let res = PathBuilder::default()
+            .protocol(Protocol::EIP)
+            .gateway("192.168.1.120")
+            .plc(PlcKind::ControlLogix)
+            .path("1,0")
+            .data_file("X:abc")
+            .build();
+        assert!(res.is_err());
+        // a symbolic name replaces the data-file address
+        let res = PathBuilder::default()
+            .protocol(Protocol::EIP)
+            .gateway("192.168.1.120")
+            .plc(PlcKind::ControlLogix)
+            .path("1,0")
+            .data_file("X:abc")
+            .name("MyTag1")
+            .build();
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_ipv6_gateway() {
+        let build = |gateway: &str| {
+            PathBuilder::default()
+                .protocol(Protocol::EIP)
+                .gateway(gateway)
+                .plc(PlcKind::ControlLogix)
+                .path("1,0")
+                .name("MyTag1")
+                .build()
+                .unwrap()
+        };
+        assert_eq!(
+            build("[::1]:44818"),
+            "protocol=ab-eip&plc=controllogix&gateway=[::1]:44818&path=1,0&name=MyTag1"
+        );
+        assert_eq!(
+            build("[fe80::1]"),
+            "protocol=ab-eip&plc=controllogix&gateway=[fe80::1]&path=1,0&name=MyTag1"
+        );
+
+        let builder = PathBuilder::parse(&build("[::1]:44818")).unwrap();
+        assert_eq!(builder.gateway.as_deref(), Some("[::1]:44818"));
+    }
+
+    #[test]
+    fn test_use_connected_msg() {
+        let build = |connected: Option<bool>| {
+            let mut builder = PathBuilder::default();
+            builder
+                .protocol(Protocol::EIP)
+                .gateway("192.168.1.120")
+                .plc(PlcKind::ControlLogix)
+                .path("1,0")
+                .name("MyTag1");
+            if let Some(yes) = connected {
+                builder.use_connected_msg(yes);
+            }
+            builder.build().unwrap()
+        };
+        assert!(build(Some(true)).contains("&plc=controllogix&use_connected_msg=1&"));
+        assert!(build(Some(false)).contains("&plc=controllogix&use_connected_msg=0&"));
+        assert!(!build(None).contains("use_connected_msg"));
+    }
+
+    #[test]
+    fn test_gateway() {
+        let valid = [
+            "192.168.1.120",
+            "192.168.1.120:502",
+            "[fe80::1]",
+            "[fe80::1]:44818",
+            "::1",
+            "localhost",
+            "plc-01.factory.local:502",
+        ];
+        for gateway in valid {
+            assert!(check_gateway(gateway).is_ok(), "{}", gateway);
+        }
+        let invalid = [
+            "",
+            "192.168.1",
+            "192.168.1.256",
+            "host:99999",
+            "host:0",
+            "host:",
+            "[fe80::1",
+            "[fe80::1]502",
+            "bad host",
+            "-plc.local",
+            "plc..local",
+        ];
+        for gateway in invalid {
+            assert!(check_gateway(gateway).is_err(), "{}", gateway);
+        }
+
+        let res = PathBuilder::default()
+            .protocol(Protocol::ModBus)
+            .gateway("host:99999")
+            .path("0")
+            .name("hr10")
+            .element_size(2)
+            .build();
+        assert!(res.is_err());
+    }
 }
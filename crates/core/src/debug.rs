@@ -4,7 +4,9 @@
 // Copyright: 2022, Joylei <leingliu@gmail.com>
 // License: MIT
 
+use crate::ffi;
 use core::convert::From;
+use std::ffi::CString;
 
 /// provides debugging output when enabled
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -38,3 +40,21 @@ impl From<u8> for DebugLevel {
         }
     }
 }
+
+/// set debug level of `libplctag`
+///
+/// # Note
+/// `libplctag` prints logs to stdout unless you register your own logger
+#[inline]
+pub fn set_debug_level(debug: DebugLevel) {
+    let level = debug as u8;
+    unsafe { ffi::plc_tag_set_debug_level(level as i32) };
+}
+
+/// retrieve debug level of `libplctag`
+#[inline]
+pub fn get_debug_level() -> DebugLevel {
+    let attr = CString::new("debug").unwrap();
+    let v = unsafe { ffi::plc_tag_get_int_attribute(0, attr.as_ptr(), 0) };
+    (v as u8).into()
+}
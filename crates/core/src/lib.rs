@@ -74,15 +74,82 @@ pub mod ffi {
 #[cfg(feature = "builder")]
 pub mod builder;
 mod debug;
+#[cfg(feature = "mock")]
+mod mock;
 mod raw;
 mod status;
+mod tag;
+mod tag_info;
 #[cfg(feature = "value")]
 mod value;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 /// plctag result
 pub type Result<T> = std::result::Result<T, Status>;
-pub use raw::RawTag;
-pub use status::Status;
+pub use debug::{get_debug_level, set_debug_level, DebugLevel};
+pub use raw::{ByteOrder, Operation, OrderedValue, RawTag, TagGuard};
+pub use status::{Status, StatusCategory};
+pub use tag::Tag;
+pub use tag_info::TagInfo;
+
+#[cfg(feature = "mock")]
+pub use mock::MockTag;
 
 #[cfg(feature = "value")]
-pub use value::{Decode, Encode, ValueExt};
+pub use value::{Decode, Encode, EnumValue, FixedString, TypedArrayView, ValueExt};
+
+/// version of the loaded `libplctag` library, in (major, minor, patch)
+#[inline]
+pub fn library_version() -> (u32, u32, u32) {
+    let get = |attr: &str| {
+        let attr = std::ffi::CString::new(attr).unwrap();
+        let v = unsafe { ffi::plc_tag_get_int_attribute(0, attr.as_ptr(), 0) };
+        v.max(0) as u32
+    };
+    (
+        get("version_major"),
+        get("version_minor"),
+        get("version_patch"),
+    )
+}
+
+/// whether `libplctag` was shutdown and no tag has been created since
+static SHUTDOWN: AtomicBool = AtomicBool::new(false);
+
+/// shutdown `libplctag`, destroying all tags and releasing global resources and background threads.
+/// it's safe to call it more than once, only the first call after a tag creation shuts the library down;
+/// the library initializes itself again on the next tag creation.
+///
+/// Normally you do not need to call this function, `libplctag` cleans up at process exit.
+///
+/// # Note
+/// drop all [`RawTag`]s before this function, and do not use the library from other threads until it returns.
+/// a [`RawTag`] created before must not be used afterwards: its operations fail as the tag is destroyed,
+/// and its tag id might be taken by a new tag, that it would destroy when dropped.
+#[inline]
+pub fn shutdown() {
+    if !SHUTDOWN.swap(true, Ordering::AcqRel) {
+        unsafe {
+            ffi::plc_tag_shutdown();
+        }
+    }
+}
+
+/// the library is initialized again by a tag creation, see [`shutdown`]
+#[inline]
+pub(crate) fn on_tag_created() {
+    SHUTDOWN.store(false, Ordering::Release);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_library_version() {
+        let version = library_version();
+        assert_ne!(version, (0, 0, 0));
+        assert!(version.0 >= 2);
+    }
+}
@@ -0,0 +1,225 @@
+// plctag-rs
+//
+// a rust wrapper of libplctag, with rust style APIs and useful extensions.
+// Copyright: 2022, Joylei <leingliu@gmail.com>
+// License: MIT
+
+use crate::tag::with_values;
+use crate::{ffi, Result, Status, Tag};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+macro_rules! mock_value_impl {
+    ($($get:ident, $set:ident: $type:ty;)*) => {
+        $(
+            #[inline]
+            fn $get(&self, byte_offset: u32) -> Result<$type> {
+                const SIZE: usize = std::mem::size_of::<$type>();
+                let mut buf = [0; SIZE];
+                buf.copy_from_slice(self.range(&self.local(), byte_offset, SIZE)?);
+                Ok(<$type>::from_le_bytes(buf))
+            }
+
+            #[inline]
+            fn $set(&self, byte_offset: u32, value: $type) -> Result<()> {
+                let buf = value.to_le_bytes();
+                let mut local = self.local();
+                self.range_mut(&mut local, byte_offset, buf.len())?
+                    .copy_from_slice(&buf);
+                Ok(())
+            }
+        )*
+    };
+}
+
+/// in-memory tag for tests, no PLC controller required.
+/// values are little endian, like tags of Logix controllers.
+///
+/// [`Tag::read`] copies the remote buffer, i.e. the value in PLC controller, into the local buffer;
+/// [`Tag::write`] copies the local buffer into the remote buffer.
+///
+/// # Examples
+/// ```rust
+/// use plctag_core::{MockTag, Tag};
+///
+/// let tag = MockTag::with_remote(vec![42, 0, 0, 0]);
+/// assert!(tag.read(100).is_ok());
+/// assert_eq!(tag.get_i32(0).unwrap(), 42);
+///
+/// tag.set_i32(0, 100).unwrap();
+/// assert!(tag.write(100).is_ok());
+/// assert_eq!(tag.remote(), [100, 0, 0, 0]);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTag {
+    local: Mutex<Vec<u8>>,
+    remote: Mutex<Vec<u8>>,
+}
+
+impl MockTag {
+    /// create [`MockTag`] of `size` bytes, all zeros
+    #[inline]
+    pub fn new(size: usize) -> Self {
+        Self::with_remote(vec![0; size])
+    }
+
+    /// create [`MockTag`] with the value in PLC controller, the local buffer is all zeros until read
+    #[inline]
+    pub fn with_remote(data: impl Into<Vec<u8>>) -> Self {
+        let remote = data.into();
+        Self {
+            local: Mutex::new(vec![0; remote.len()]),
+            remote: Mutex::new(remote),
+        }
+    }
+
+    /// the value in PLC controller, updated by [`Tag::write`]
+    #[inline]
+    pub fn remote(&self) -> Vec<u8> {
+        lock(&self.remote).clone()
+    }
+
+    /// change the value in PLC controller, seen by the next [`Tag::read`]; the size of the tag changes with it
+    #[inline]
+    pub fn set_remote(&self, data: impl Into<Vec<u8>>) {
+        *lock(&self.remote) = data.into();
+    }
+
+    #[inline]
+    fn local(&self) -> MutexGuard<'_, Vec<u8>> {
+        lock(&self.local)
+    }
+
+    #[inline]
+    fn range<'a>(&self, buf: &'a [u8], byte_offset: u32, len: usize) -> Result<&'a [u8]> {
+        let start = byte_offset as usize;
+        buf.get(start..start + len)
+            .ok_or(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))
+    }
+
+    #[inline]
+    fn range_mut<'a>(
+        &self,
+        buf: &'a mut [u8],
+        byte_offset: u32,
+        len: usize,
+    ) -> Result<&'a mut [u8]> {
+        let start = byte_offset as usize;
+        buf.get_mut(start..start + len)
+            .ok_or(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))
+    }
+}
+
+#[inline]
+fn lock(buf: &Mutex<Vec<u8>>) -> MutexGuard<'_, Vec<u8>> {
+    buf.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Tag for MockTag {
+    #[inline]
+    fn read(&self, _timeout: u32) -> Status {
+        *self.local() = self.remote();
+        Status::Ok
+    }
+
+    #[inline]
+    fn write(&self, _timeout: u32) -> Status {
+        let local = self.local().clone();
+        self.set_remote(local);
+        Status::Ok
+    }
+
+    #[inline]
+    fn status(&self) -> Status {
+        Status::Ok
+    }
+
+    #[inline]
+    fn size(&self) -> Result<u32> {
+        Ok(self.local().len() as u32)
+    }
+
+    #[inline]
+    fn get_bytes(&self, byte_offset: u32, buf: &mut [u8]) -> Result<usize> {
+        let local = self.local();
+        let start = std::cmp::min(byte_offset as usize, local.len());
+        let len = std::cmp::min(local.len() - start, buf.len());
+        buf[..len].copy_from_slice(&local[start..start + len]);
+        Ok(len)
+    }
+
+    #[inline]
+    fn set_bytes(&self, byte_offset: u32, buf: &[u8]) -> Result<usize> {
+        let mut local = self.local();
+        let start = std::cmp::min(byte_offset as usize, local.len());
+        let len = std::cmp::min(local.len() - start, buf.len());
+        local[start..start + len].copy_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    #[inline]
+    fn get_bit(&self, bit_offset: u32) -> Result<bool> {
+        let byte = self.get_u8(bit_offset / 8)?;
+        Ok(byte & (1 << (bit_offset % 8)) != 0)
+    }
+
+    #[inline]
+    fn set_bit(&self, bit_offset: u32, value: bool) -> Result<()> {
+        let byte = self.get_u8(bit_offset / 8)?;
+        let mask = 1 << (bit_offset % 8);
+        let byte = if value { byte | mask } else { byte & !mask };
+        self.set_u8(bit_offset / 8, byte)
+    }
+
+    with_values!(mock_value_impl);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_write() {
+        let tag = MockTag::with_remote(vec![1, 2, 3, 4]);
+        assert_eq!(tag.size().unwrap(), 4);
+        assert_eq!(tag.get_u32(0).unwrap(), 0);
+
+        assert!(tag.read(100).is_ok());
+        assert_eq!(tag.get_u32(0).unwrap(), 0x04030201);
+        assert_eq!(tag.get_u16(2).unwrap(), 0x0403);
+
+        tag.set_f32(0, 1.5).unwrap();
+        assert_eq!(tag.remote(), [1, 2, 3, 4]);
+        assert!(tag.write(100).is_ok());
+        assert_eq!(tag.remote(), 1.5_f32.to_le_bytes());
+
+        tag.set_remote(vec![0xFF; 8]);
+        assert!(tag.read(100).is_ok());
+        assert_eq!(tag.get_i64(0).unwrap(), -1);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let tag = MockTag::new(4);
+        assert!(tag.get_u32(1).is_err());
+        assert!(tag.set_u16(3, 1).is_err());
+        assert!(tag.get_bit(32).is_err());
+
+        let mut buf = [0; 8];
+        assert_eq!(tag.set_bytes(2, &[9; 8]).unwrap(), 2);
+        assert_eq!(tag.get_bytes(0, &mut buf).unwrap(), 4);
+        assert_eq!(buf[..4], [0, 0, 9, 9]);
+        assert_eq!(tag.get_bytes(10, &mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bit() {
+        let tag = MockTag::new(2);
+        tag.set_bit(9, true).unwrap();
+        assert!(tag.get_bit(9).unwrap());
+        assert!(!tag.get_bit(8).unwrap());
+        assert_eq!(tag.get_u16(0).unwrap(), 0x0200);
+
+        tag.set_bit(9, false).unwrap();
+        assert_eq!(tag.get_u16(0).unwrap(), 0);
+    }
+}
@@ -7,19 +7,38 @@
 use crate::*;
 use std::{
     ffi::{c_void, CString},
+    fmt,
+    marker::PhantomData,
+    ops::Deref,
     thread,
     time::{Duration, Instant}
 };
 
+#[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
 /// wrapper of tag model based on `libplctag`
-#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RawTag {
     tag_id: i32,
+    /// creation path, for debugging
+    #[cfg_attr(feature = "serde", serde(skip))]
+    path: Option<String>,
+    /// destroy the tag when dropped?
+    #[cfg_attr(feature = "serde", serde(skip, default = "owned"))]
+    owned: bool,
+}
+
+#[cfg(feature = "serde")]
+#[inline(always)]
+fn owned() -> bool {
+    true
 }
 
 impl RawTag {
+    /// timeout in milliseconds to create a tag when none is given, i.e. by `TryFrom<PathBuilder>`
+    pub const DEFAULT_TIMEOUT: u32 = 5000;
+
     /// create new [`RawTag`]
     ///
     /// # Note
@@ -40,10 +59,60 @@ impl RawTag {
     pub fn new<P: Into<Vec<u8>>>(path: P, timeout: u32) -> Result<Self> {
         let path = CString::new(path).unwrap();
         let tag_id = unsafe { ffi::plc_tag_create(path.as_ptr(), timeout as i32) };
+        crate::on_tag_created();
         if tag_id < 0 {
             return Err(Status::new(tag_id));
         }
-        Ok(Self { tag_id })
+        Ok(Self {
+            tag_id,
+            path: path.into_string().ok(),
+            owned: true,
+        })
+    }
+
+    /// create new [`RawTag`] from the path of a [`PathBuilder`](crate::builder::PathBuilder).
+    /// returns a bad param error if the builder fails to build the path.
+    ///
+    /// if the element type is set by [`PathBuilder::element_type`](crate::builder::PathBuilder::element_type),
+    /// a bad config error is returned when the tag size reported by the PLC does not match it;
+    /// the size is only checked if the tag is created within the timeout.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use plctag_core::builder::*;
+    /// use plctag_core::RawTag;
+    ///
+    /// let mut builder = PathBuilder::default();
+    /// builder
+    ///     .protocol(Protocol::EIP)
+    ///     .gateway("192.168.1.120")
+    ///     .plc(PlcKind::ControlLogix)
+    ///     .name("MyTag1")
+    ///     .path("1,0");
+    /// let tag = RawTag::from_builder(&builder, 1000).unwrap();
+    /// ```
+    #[cfg(feature = "builder")]
+    pub fn from_builder(builder: &crate::builder::PathBuilder, timeout: u32) -> Result<Self> {
+        let path = builder
+            .build()
+            .map_err(|_| Status::Err(ffi::PLCTAG_ERR_BAD_PARAM))?;
+        let tag = Self::new(path, timeout)?;
+        if let Some(expected) = builder.expected_size() {
+            if tag.status().is_ok() {
+                tag.check_size(expected)?;
+            }
+        }
+        Ok(tag)
+    }
+
+    /// check that the tag size equals the expected size in bytes
+    #[cfg(feature = "builder")]
+    #[inline]
+    fn check_size(&self, expected: usize) -> Result<()> {
+        if self.size()? as usize != expected {
+            return Err(Status::Err(ffi::PLCTAG_ERR_BAD_CONFIG));
+        }
+        Ok(())
     }
 
     /// create new [`RawTag`]
@@ -64,10 +133,15 @@ impl RawTag {
     ) -> Result<Self> {
         let path = CString::new(path).unwrap();
         let tag_id = ffi::plc_tag_create_ex(path.as_ptr(), cb, user_data, timeout as i32);
+        crate::on_tag_created();
         if tag_id < 0 {
             return Err(Status::new(tag_id));
         }
-        Ok(Self { tag_id })
+        Ok(Self {
+            tag_id,
+            path: path.into_string().ok(),
+            owned: true,
+        })
     }
 
     /// tag id
@@ -76,6 +150,69 @@ impl RawTag {
         self.tag_id
     }
 
+    /// creation path, `None` for tags from [`RawTag::from_raw`]
+    #[inline(always)]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// the raw tag id to pass to `libplctag`, the tag is still destroyed when the [`RawTag`] drops.
+    /// see [`RawTag::into_raw`] to take the ownership.
+    #[inline(always)]
+    pub fn as_raw(&self) -> i32 {
+        self.tag_id
+    }
+
+    /// take the tag id, the tag will not be destroyed by drop any more.
+    /// call [`RawTag::from_raw`] with `owned = true` to get the ownership back, or destroy it by `plc_tag_destroy`.
+    #[inline]
+    pub fn into_raw(mut self) -> i32 {
+        let tag_id = self.tag_id;
+        self.path = None;
+        std::mem::forget(self);
+        tag_id
+    }
+
+    /// create [`RawTag`] from the id of a tag created by `libplctag`.
+    /// the tag is destroyed when dropped if `owned` is true; pass false to use a tag owned by others.
+    ///
+    /// # Safety
+    /// the tag id must be valid, and must not be destroyed by others while the [`RawTag`] is alive;
+    /// only one owning [`RawTag`] is allowed for the same tag id.
+    #[inline]
+    pub unsafe fn from_raw(tag_id: i32, owned: bool) -> Self {
+        Self {
+            tag_id,
+            path: None,
+            owned,
+        }
+    }
+
+    /// create a second tag of the same path, i.e. a new handle with its own buffer and status.
+    /// returns an unsupported error for tags from [`RawTag::from_raw`], their path is unknown.
+    ///
+    /// both tags can be used from different threads; to share one handle instead, wrap the tag in an `Arc`,
+    /// [`RawTag`] is `Send + Sync` and `libplctag` serializes operations of the same tag.
+    /// callbacks are not cloned.
+    #[inline]
+    pub fn try_clone(&self, timeout: u32) -> Result<Self> {
+        match self.path {
+            Some(ref path) => Self::new(path.as_str(), timeout),
+            None => Err(Status::Err(ffi::PLCTAG_ERR_UNSUPPORTED)),
+        }
+    }
+
+    /// destroy the tag and create it again from the same path, i.e. to recover after the PLC reboots.
+    /// the tag is left unchanged if the creation fails; returns an unsupported error for tags from [`RawTag::from_raw`].
+    ///
+    /// it takes `&mut self`, so no other operation runs on the tag meanwhile;
+    /// a tag shared by `Arc` can not be reinitialized, create a new one instead. callbacks are not kept.
+    #[inline]
+    pub fn reinitialize(&mut self, timeout: u32) -> Result<()> {
+        *self = self.try_clone(timeout)?;
+        Ok(())
+    }
+
     /// perform read operation.
     /// - blocking read if timeout > 0
     /// - non-blocking read if timeout = 0
@@ -128,6 +265,32 @@ impl RawTag {
         self.get_attr("elem_count", 0)
     }
 
+    /// interval in milliseconds to read the tag in background, 0 if disabled.
+    #[inline(always)]
+    pub fn auto_sync_read_ms(&self) -> Result<i32> {
+        self.get_attr("auto_sync_read_ms", 0)
+    }
+
+    /// set interval in milliseconds to read the tag in background, 0 to disable.
+    /// the tag buffer is refreshed without calling [`RawTag::read`]; a manual read still performs an immediate read.
+    #[inline(always)]
+    pub fn set_auto_sync_read_ms(&self, millis: u32) -> Result<()> {
+        self.set_attr("auto_sync_read_ms", millis as i32)
+    }
+
+    /// interval in milliseconds to buffer changes before writing the tag in background, 0 if disabled.
+    #[inline(always)]
+    pub fn auto_sync_write_ms(&self) -> Result<i32> {
+        self.get_attr("auto_sync_write_ms", 0)
+    }
+
+    /// set interval in milliseconds to buffer changes before writing the tag in background, 0 to disable.
+    /// changes of the tag buffer are written without calling [`RawTag::write`]; a manual write still performs an immediate write.
+    #[inline(always)]
+    pub fn set_auto_sync_write_ms(&self, millis: u32) -> Result<()> {
+        self.set_attr("auto_sync_write_ms", millis as i32)
+    }
+
     /// get tag attribute
     #[inline(always)]
     pub fn get_attr(&self, attr: impl AsRef<str>, default_value: i32) -> Result<i32> {
@@ -149,7 +312,9 @@ impl RawTag {
         Status::new(rc).into_result()
     }
 
-    /// poll tag status
+    /// poll tag status.
+    /// it only queries the status of the tag, never starts a read or write operation;
+    /// use it to check whether a non-blocking [`RawTag::read`] or [`RawTag::write`] has completed.
     #[inline(always)]
     pub fn status(&self) -> Status {
         let rc = unsafe { ffi::plc_tag_status(self.tag_id) };
@@ -176,18 +341,21 @@ impl RawTag {
         Ok(value as u32)
     }
 
-    /// get bit value
+    /// get bit value.
+    /// `bit_offset` is a bit index into the whole tag buffer, not a byte offset;
+    /// e.g. bit 10 is the third bit of the second byte.
     #[inline(always)]
     pub fn get_bit(&self, bit_offset: u32) -> Result<bool> {
         let val = unsafe { ffi::plc_tag_get_bit(self.tag_id, bit_offset as i32) };
-        if val == i32::MIN {
+        if val < 0 {
             // error
-            return Err(self.status());
+            return Err(Status::from(val));
         }
         Ok(val == 1)
     }
 
-    /// set bit value
+    /// set bit value.
+    /// `bit_offset` is a bit index into the whole tag buffer, see [`RawTag::get_bit`]
     #[inline(always)]
     pub fn set_bit(&self, bit_offset: u32, value: bool) -> Result<()> {
         let rc = unsafe {
@@ -196,6 +364,47 @@ impl RawTag {
         Status::new(rc).into_result()
     }
 
+    /// get `len` bits from `start_bit` as an unsigned value, the bit at `start_bit` is the least significant bit.
+    /// `len` must be in range [1-32]; you should call read() before this operation.
+    #[inline]
+    pub fn read_bits(&self, start_bit: u32, len: u32) -> Result<u32> {
+        self.check_bit_range(start_bit, len)?;
+        let mut value = 0;
+        for i in 0..len {
+            if self.get_bit(start_bit + i)? {
+                value |= 1 << i;
+            }
+        }
+        Ok(value)
+    }
+
+    /// set `len` bits from `start_bit` to an unsigned value, the bit at `start_bit` is the least significant bit.
+    /// `len` must be in range [1-32] and `value` must fit in `len` bits; you should call write() later.
+    #[inline]
+    pub fn write_bits(&self, start_bit: u32, len: u32, value: u32) -> Result<()> {
+        self.check_bit_range(start_bit, len)?;
+        if len < 32 && value >> len != 0 {
+            return Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE));
+        }
+        for i in 0..len {
+            self.set_bit(start_bit + i, value & (1 << i) != 0)?;
+        }
+        Ok(())
+    }
+
+    /// check that `len` bits from `start_bit` are in the tag buffer
+    #[inline]
+    fn check_bit_range(&self, start_bit: u32, len: u32) -> Result<()> {
+        if len == 0 || len > 32 {
+            return Err(Status::Err(ffi::PLCTAG_ERR_BAD_PARAM));
+        }
+        let size = self.size()? as u64;
+        if start_bit as u64 + len as u64 > size * 8 {
+            return Err(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS));
+        }
+        Ok(())
+    }
+
     /// get bool value
     #[inline(always)]
     pub fn get_bool(&self, byte_offset: u32) -> Result<bool> {
@@ -379,6 +588,90 @@ impl RawTag {
         Status::new(rc).into_result()
     }
 
+    /// get numeric value from raw bytes in specified byte order, regardless of the tag's byte order attributes
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use plctag_core::{ByteOrder, RawTag};
+    /// let timeout = 100;//ms
+    /// let path="protocol=ab-eip&plc=slc500&gateway=192.168.1.120&name=F8:0&elem_count=1";// YOUR TAG DEFINITION
+    /// let tag = RawTag::new(path, timeout).unwrap();
+    ///
+    /// let status = tag.read(timeout);
+    /// assert!(status.is_ok());
+    /// let value: f32 = tag.get_value_with_order(0, ByteOrder::WordSwapped).unwrap();
+    /// println!("value: {}", value);
+    /// ```
+    #[inline]
+    pub fn get_value_with_order<T: OrderedValue>(
+        &self,
+        byte_offset: u32,
+        order: ByteOrder,
+    ) -> Result<T> {
+        T::get_ordered(self, byte_offset, order)
+    }
+
+    /// set numeric value as raw bytes in specified byte order, regardless of the tag's byte order attributes
+    #[inline]
+    pub fn set_value_with_order<T: OrderedValue>(
+        &self,
+        byte_offset: u32,
+        value: T,
+        order: ByteOrder,
+    ) -> Result<()> {
+        value.set_ordered(self, byte_offset, order)
+    }
+
+    /// get f32 value from raw bytes in specified byte order, see [`RawTag::get_value_with_order`]
+    #[inline]
+    pub fn get_f32_with_order(&self, byte_offset: u32, order: ByteOrder) -> Result<f32> {
+        self.get_value_with_order(byte_offset, order)
+    }
+
+    /// set f32 value as raw bytes in specified byte order, see [`RawTag::set_value_with_order`]
+    #[inline]
+    pub fn set_f32_with_order(&self, byte_offset: u32, value: f32, order: ByteOrder) -> Result<()> {
+        self.set_value_with_order(byte_offset, value, order)
+    }
+
+    /// get f64 value from raw bytes in specified byte order, see [`RawTag::get_value_with_order`]
+    #[inline]
+    pub fn get_f64_with_order(&self, byte_offset: u32, order: ByteOrder) -> Result<f64> {
+        self.get_value_with_order(byte_offset, order)
+    }
+
+    /// set f64 value as raw bytes in specified byte order, see [`RawTag::set_value_with_order`]
+    #[inline]
+    pub fn set_f64_with_order(&self, byte_offset: u32, value: f64, order: ByteOrder) -> Result<()> {
+        self.set_value_with_order(byte_offset, value, order)
+    }
+
+    /// read `N` raw bytes, returns them in little endian order
+    #[inline]
+    fn get_ordered_bytes<const N: usize>(
+        &self,
+        byte_offset: u32,
+        order: ByteOrder,
+    ) -> Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.get_bytes_unchecked(byte_offset, &mut buf)?;
+        order.swap(&mut buf);
+        Ok(buf)
+    }
+
+    /// write `N` little endian bytes in specified byte order
+    #[inline]
+    fn set_ordered_bytes<const N: usize>(
+        &self,
+        byte_offset: u32,
+        mut buf: [u8; N],
+        order: ByteOrder,
+    ) -> Result<()> {
+        order.swap(&mut buf);
+        self.set_bytes_unchecked(byte_offset, &buf)?;
+        Ok(())
+    }
+
     /// Getting A String Length
     #[inline(always)]
     pub fn get_string_length(&self, byte_offset: u32) -> Result<u32> {
@@ -435,6 +728,47 @@ impl RawTag {
         Status::new(rc).into_result()
     }
 
+    /// get string value from the tag buffer.
+    /// the counted string layout is handled by `libplctag` based on the PLC kind.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use plctag_core::RawTag;
+    /// let timeout = 100;//ms
+    /// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyString&elem_count=1";// YOUR TAG DEFINITION
+    /// let tag = RawTag::new(path, timeout).unwrap();
+    ///
+    /// tag.write_string(0, "héllo").unwrap();
+    /// let status = tag.write(timeout);
+    /// assert!(status.is_ok());
+    ///
+    /// let status = tag.read(timeout);
+    /// assert!(status.is_ok());
+    /// let value = tag.read_string(0).unwrap();
+    /// assert_eq!(value, "héllo");
+    /// ```
+    #[inline]
+    pub fn read_string(&self, byte_offset: u32) -> Result<String> {
+        let len = self.get_string_length(byte_offset)? as usize;
+        let mut buf = vec![0; len + 1];
+        self.get_string(byte_offset, &mut buf)?;
+        buf.truncate(len);
+        String::from_utf8(buf).map_err(|_| Status::Err(ffi::PLCTAG_ERR_BAD_DATA))
+    }
+
+    /// set string value in the tag buffer.
+    /// returns a too large error if the string exceeds the string capacity, nothing is written in that case.
+    #[inline]
+    pub fn write_string(&self, byte_offset: u32, value: &str) -> Result<()> {
+        let capacity = self.get_string_capacity(byte_offset)? as usize;
+        if value.len() > capacity {
+            return Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE));
+        }
+        let buf = CString::new(value).map_err(|_| Status::Err(ffi::PLCTAG_ERR_BAD_PARAM))?;
+        let rc = unsafe { ffi::plc_tag_set_string(self.tag_id, byte_offset as i32, buf.as_ptr()) };
+        Status::new(rc).into_result()
+    }
+
     /// get raw bytes.
     /// If buffer length would exceed the end of the data in the tag data buffer, an out of bounds error is returned
     #[inline(always)]
@@ -499,6 +833,212 @@ impl RawTag {
         self.set_bytes_unchecked(byte_offset, buf)
     }
 
+    /// zero the whole tag buffer.
+    /// it only affects the local buffer, the PLC is not touched until the next [`RawTag::write`]
+    #[inline]
+    pub fn clear_buffer(&self) -> Result<()> {
+        let size = self.size()? as usize;
+        if size > 0 {
+            self.set_bytes_unchecked(0, &vec![0; size])?;
+        }
+        Ok(())
+    }
+
+    /// parse the tag buffer as a `@tags` listing, you should call read() before this operation.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use plctag_core::RawTag;
+    /// let timeout = 1000;//ms
+    /// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=@tags";
+    /// let tag = RawTag::new(path, timeout).unwrap();
+    ///
+    /// let status = tag.read(timeout);
+    /// assert!(status.is_ok());
+    /// for info in tag.list_tags().unwrap() {
+    ///     println!("{}: type={:#x}", info.name, info.type_code);
+    /// }
+    /// ```
+    #[inline]
+    pub fn list_tags(&self) -> Result<Vec<TagInfo>> {
+        let size = self.size()? as usize;
+        let mut buf = vec![0; size];
+        self.get_bytes_unchecked(0, &mut buf)?;
+        TagInfo::parse_list(&buf)
+    }
+
+    /// get `count` values of `T` from the tag buffer.
+    /// values are laid out every [`Decode::wire_size`] bytes from the byte offset, a `bool` value is the first bit of its byte;
+    /// an out of bounds error is returned if they exceed the end of the tag buffer.
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn get_values<T: Decode>(&self, byte_offset: u32, count: usize) -> Result<Vec<T>> {
+        let stride = T::wire_size();
+        self.check_values(byte_offset, count, stride)?;
+        (0..count)
+            .map(|i| T::decode_at_byte(self, value_offset(byte_offset, i, stride)?))
+            .collect()
+    }
+
+    /// set values of `T` in the tag buffer.
+    /// values are laid out every [`Encode::wire_size`] bytes from the byte offset, a `bool` value is the first bit of its byte;
+    /// an out of bounds error is returned if they exceed the end of the tag buffer.
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn set_values<T: Encode>(&self, byte_offset: u32, values: &[T]) -> Result<()> {
+        let stride = T::wire_size();
+        self.check_values(byte_offset, values.len(), stride)?;
+        for (i, value) in values.iter().enumerate() {
+            value.encode_at_byte(self, value_offset(byte_offset, i, stride)?)?;
+        }
+        Ok(())
+    }
+
+    /// typed view over the tag buffer, you should call read() before this operation.
+    /// the number of elements is `size() / T::wire_size()`.
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn array_view<T: Decode>(&self) -> Result<TypedArrayView<'_, T>> {
+        TypedArrayView::new(self)
+    }
+
+    /// perform blocking read operation, then get `count` values of `T`, see [`RawTag::get_values`]
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn read_values<T: Decode>(
+        &self,
+        byte_offset: u32,
+        count: usize,
+        timeout: u32,
+    ) -> Result<Vec<T>> {
+        self.read(timeout).into_result()?;
+        self.get_values(byte_offset, count)
+    }
+
+    /// perform blocking read operation, then decode the value of `T` into `out`
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn read_into<T: Decode>(&self, byte_offset: u32, out: &mut T, timeout: u32) -> Result<()> {
+        self.read(timeout).into_result()?;
+        T::decode_in_place(self, byte_offset, out)
+    }
+
+    /// perform blocking read operation, then get `count` values of `T` into `out`.
+    /// `out` is cleared first, its allocation is reused, see [`RawTag::get_values`]
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn read_values_into<T: Decode>(
+        &self,
+        byte_offset: u32,
+        count: usize,
+        out: &mut Vec<T>,
+        timeout: u32,
+    ) -> Result<()> {
+        self.read(timeout).into_result()?;
+        let stride = T::wire_size();
+        self.check_values(byte_offset, count, stride)?;
+        out.clear();
+        out.reserve(count);
+        for i in 0..count {
+            out.push(T::decode_at_byte(
+                self,
+                value_offset(byte_offset, i, stride)?,
+            )?);
+        }
+        Ok(())
+    }
+
+    /// set values of `T`, then perform blocking write operation, see [`RawTag::set_values`]
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn write_values<T: Encode>(
+        &self,
+        byte_offset: u32,
+        values: &[T],
+        timeout: u32,
+    ) -> Result<()> {
+        self.set_values(byte_offset, values)?;
+        self.write(timeout).into_result()
+    }
+
+    /// set the value and perform blocking write operation only if it differs from the value in the tag buffer;
+    /// returns whether a write was performed.
+    ///
+    /// it compares against the local tag buffer, so the buffer should be fresh, i.e. read() recently.
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn write_if_changed<T: Decode + Encode + PartialEq>(
+        &self,
+        byte_offset: u32,
+        value: T,
+        timeout: u32,
+    ) -> Result<bool> {
+        let current = T::decode(self, byte_offset)?;
+        if current == value {
+            return Ok(false);
+        }
+        value.encode(self, byte_offset)?;
+        self.write(timeout).into_result()?;
+        Ok(true)
+    }
+
+    /// perform blocking read operation every `poll` until the value of `T` satisfies `pred`, returns the matching value.
+    /// a timeout error is returned if the value does not satisfy `pred` within `timeout`.
+    ///
+    /// # Note
+    /// only for simple use cases, i.e. test harnesses
+    #[cfg(feature = "value")]
+    #[inline]
+    pub fn wait_until<T, F>(
+        &self,
+        byte_offset: u32,
+        pred: F,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<T>
+    where
+        T: Decode,
+        F: Fn(&T) -> bool,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            // at least 1ms, 0 means non-blocking
+            let millis = remaining.as_millis().clamp(1, u32::MAX as u128) as u32;
+            self.read(millis).into_result()?;
+            let value = T::decode(self, byte_offset)?;
+            if pred(&value) {
+                return Ok(value);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Status::Err(ffi::PLCTAG_ERR_TIMEOUT));
+            }
+            thread::sleep(poll.min(remaining));
+        }
+    }
+
+    /// check that `len` bytes from the byte offset are in the tag buffer
+    #[cfg(feature = "value")]
+    #[inline]
+    fn check_bounds(&self, byte_offset: u32, len: usize) -> Result<()> {
+        let size = self.size()? as usize;
+        match (byte_offset as usize).checked_add(len) {
+            Some(end) if end <= size => Ok(()),
+            _ => Err(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS)),
+        }
+    }
+
+    /// check that `count` values laid out every `stride` bytes from the byte offset are in the tag buffer
+    #[cfg(feature = "value")]
+    #[inline]
+    fn check_values(&self, byte_offset: u32, count: usize, stride: usize) -> Result<()> {
+        let len = count
+            .checked_mul(stride)
+            .ok_or(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))?;
+        self.check_bounds(byte_offset, len)
+    }
+
     /// note: registering a new callback will override existing one
     ///
     /// # Safety
@@ -545,16 +1085,270 @@ impl RawTag {
     /// The operation is only needed when you write async code.
     /// For non-blocking read/write (timeout=0), it's your responsibility to call this method to cancel the pending
     /// operation when timeout or other necessary situations.
+    ///
+    /// After aborting, the tag status returns to [`Status::Ok`], but the contents of the tag buffer are undefined;
+    /// perform another read before getting values from the tag.
     #[inline(always)]
     pub fn abort(&self) -> Result<()> {
         let rc = unsafe { ffi::plc_tag_abort(self.tag_id) };
         Status::new(rc).into_result()
     }
+
+    /// start a non-blocking read operation.
+    /// the returned [`Operation`] aborts the read if dropped before completion.
+    #[inline]
+    pub fn start_read(&self) -> Result<Operation<'_>> {
+        Operation::start(self, self.read(0))
+    }
+
+    /// start a non-blocking write operation.
+    /// the returned [`Operation`] aborts the write if dropped before completion.
+    #[inline]
+    pub fn start_write(&self) -> Result<Operation<'_>> {
+        Operation::start(self, self.write(0))
+    }
+
+    /// lock the tag by `plc_tag_lock` until the returned [`TagGuard`] drops.
+    /// other threads calling [`RawTag::lock`] and the auto sync of `libplctag` block meanwhile;
+    /// other operations, e.g. `get_*`/`set_*`, do not take the lock, so they are not blocked.
+    /// the lock is recursive, the current thread can still access the tag through the guard.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use plctag_core::RawTag;
+    /// let timeout = 100;//ms
+    /// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyTag1&elem_count=1&elem_size=16&auto_sync_read_ms=200";
+    /// let tag = RawTag::new(path, timeout).unwrap();
+    ///
+    /// let guard = tag.lock().unwrap();
+    /// let value = guard.get_u16(0).unwrap();
+    /// guard.set_u16(0, value + 1).unwrap();
+    /// guard.write(timeout).into_result().unwrap();
+    /// ```
+    #[inline]
+    pub fn lock(&self) -> Result<TagGuard<'_>> {
+        let rc = unsafe { ffi::plc_tag_lock(self.tag_id) };
+        Status::new(rc).into_result()?;
+        Ok(TagGuard {
+            tag: self,
+            _not_send: PhantomData,
+        })
+    }
+}
+
+/// guard of a locked tag, see [`RawTag::lock`]. the tag is unlocked by `plc_tag_unlock` when the guard drops.
+///
+/// the lock is owned by the thread that locked the tag, so the guard can not be sent to another thread:
+/// ```rust,compile_fail
+/// use plctag_core::RawTag;
+///
+/// fn assert_send<T: Send>(_: T) {}
+///
+/// let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+/// assert_send(tag.lock().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct TagGuard<'a> {
+    tag: &'a RawTag,
+    /// `!Send`, like `std::sync::MutexGuard`
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Deref for TagGuard<'_> {
+    type Target = RawTag;
+    #[inline]
+    fn deref(&self) -> &RawTag {
+        self.tag
+    }
+}
+
+impl Drop for TagGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            ffi::plc_tag_unlock(self.tag.tag_id);
+        }
+    }
+}
+
+/// guard of a pending read/write operation, see [`RawTag::start_read`] and [`RawTag::start_write`].
+/// the operation is aborted by [`RawTag::abort`] if the guard drops before the operation completes.
+#[derive(Debug)]
+pub struct Operation<'a> {
+    tag: &'a RawTag,
+    pending: bool,
+}
+
+impl<'a> Operation<'a> {
+    #[inline]
+    fn start(tag: &'a RawTag, status: Status) -> Result<Self> {
+        match status {
+            Status::Err(_) => Err(status),
+            _ => Ok(Self {
+                tag,
+                pending: status.is_pending(),
+            }),
+        }
+    }
+
+    /// wait until the operation completes, blocking, see [`RawTag::wait`].
+    /// the operation is aborted on timeout.
+    #[inline]
+    pub fn complete(mut self, timeout: Option<Duration>) -> Result<()> {
+        if !self.pending {
+            return Ok(());
+        }
+        let status = self.tag.wait(timeout);
+        if !status.is_timeout() {
+            self.pending = false;
+        }
+        status.into_result()
+    }
+}
+
+impl Drop for Operation<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.pending {
+            let _ = self.tag.abort();
+        }
+    }
+}
+
+/// byte offset of the value at `index` of values laid out every `stride` bytes from the byte offset
+#[cfg(feature = "value")]
+#[inline]
+fn value_offset(byte_offset: u32, index: usize, stride: usize) -> Result<u32> {
+    index
+        .checked_mul(stride)
+        .and_then(|v| u32::try_from(v).ok())
+        .and_then(|v| byte_offset.checked_add(v))
+        .ok_or(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))
+}
+
+/// create [`RawTag`] from the builder, like [`RawTag::from_builder`] with [`RawTag::DEFAULT_TIMEOUT`].
+/// it waits until the tag is created; errors of creation, e.g. an unreachable gateway or a timeout, are returned as [`Status`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use plctag_core::builder::*;
+/// use plctag_core::RawTag;
+///
+/// let tag = RawTag::try_from(
+///     PathBuilder::default()
+///         .protocol(Protocol::EIP)
+///         .gateway("192.168.1.120")
+///         .plc(PlcKind::ControlLogix)
+///         .name("MyTag1")
+///         .path("1,0"),
+/// )
+/// .unwrap();
+/// ```
+#[cfg(feature = "builder")]
+impl TryFrom<&crate::builder::PathBuilder> for RawTag {
+    type Error = Status;
+    #[inline]
+    fn try_from(builder: &crate::builder::PathBuilder) -> Result<Self> {
+        let tag = Self::from_builder(builder, Self::DEFAULT_TIMEOUT)?;
+        tag.status().into_result()?;
+        Ok(tag)
+    }
+}
+
+/// same as `TryFrom<&PathBuilder>`
+#[cfg(feature = "builder")]
+impl TryFrom<&mut crate::builder::PathBuilder> for RawTag {
+    type Error = Status;
+    #[inline]
+    fn try_from(builder: &mut crate::builder::PathBuilder) -> Result<Self> {
+        Self::try_from(&*builder)
+    }
+}
+
+/// same as `TryFrom<&PathBuilder>`
+#[cfg(feature = "builder")]
+impl TryFrom<crate::builder::PathBuilder> for RawTag {
+    type Error = Status;
+    #[inline]
+    fn try_from(builder: crate::builder::PathBuilder) -> Result<Self> {
+        Self::try_from(&builder)
+    }
+}
+
+impl fmt::Debug for RawTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("RawTag");
+        f.field("id", &self.tag_id);
+        // unknown for tags from raw ids
+        if let Some(ref path) = self.path {
+            f.field("path", path);
+        }
+        f.field("status", &self.status()).finish()
+    }
+}
+
+/// byte order of raw values in the tag buffer
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ByteOrder {
+    /// most significant byte first, e.g. `ABCD`
+    BigEndian,
+    /// least significant byte first, e.g. `DCBA`
+    LittleEndian,
+    /// little endian 16-bit words, most significant word first, e.g. `BADC`; used by some PLC5/SLC devices
+    WordSwapped,
+}
+
+macro_rules! ordered_value_impl {
+    ($($type:ty),*) => {
+        $(
+            impl OrderedValue for $type {
+                #[inline]
+                fn get_ordered(tag: &RawTag, byte_offset: u32, order: ByteOrder) -> Result<Self> {
+                    let buf = tag.get_ordered_bytes(byte_offset, order)?;
+                    Ok(<$type>::from_le_bytes(buf))
+                }
+
+                #[inline]
+                fn set_ordered(self, tag: &RawTag, byte_offset: u32, order: ByteOrder) -> Result<()> {
+                    tag.set_ordered_bytes(byte_offset, self.to_le_bytes(), order)
+                }
+            }
+        )*
+    };
+}
+
+/// numeric values of 2, 4 or 8 bytes, see [`RawTag::get_value_with_order`]
+pub trait OrderedValue: Sized {
+    #[doc(hidden)]
+    fn get_ordered(tag: &RawTag, byte_offset: u32, order: ByteOrder) -> Result<Self>;
+
+    #[doc(hidden)]
+    fn set_ordered(self, tag: &RawTag, byte_offset: u32, order: ByteOrder) -> Result<()>;
+}
+
+ordered_value_impl!(i16, u16, i32, u32, i64, u64, f32, f64);
+
+impl ByteOrder {
+    /// convert between this order and little endian, the conversion is its own inverse
+    #[inline]
+    fn swap(self, buf: &mut [u8]) {
+        match self {
+            ByteOrder::BigEndian => buf.reverse(),
+            ByteOrder::LittleEndian => {}
+            ByteOrder::WordSwapped => {
+                buf.reverse();
+                buf.chunks_mut(2).for_each(|word| word.reverse());
+            }
+        }
+    }
 }
 
 impl Drop for RawTag {
     #[inline(always)]
     fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
         unsafe {
             //let _ = self.abort();
             ffi::plc_tag_destroy(self.tag_id);
@@ -609,4 +1403,414 @@ mod tests {
         let result = &[3, 0, 0];
         assert_eq!(&buf[0..3], result);
     }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn test_from_builder() {
+        use crate::builder::*;
+
+        let mut builder = PathBuilder::default();
+        builder
+            .protocol(Protocol::EIP)
+            .gateway("127.0.0.1")
+            .plc(PlcKind::ControlLogix)
+            .name("MyTag1")
+            .path("1,0");
+        // non-blocking create, no PLC needed
+        let tag = RawTag::from_builder(&builder, 0).unwrap();
+        assert!(tag.id() > 0);
+
+        let builder = PathBuilder::default();
+        let res = RawTag::from_builder(&builder, 0);
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn test_check_size() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        assert!(tag.check_size(30).is_ok());
+        let res = tag.check_size(4 * 4);
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_BAD_CONFIG))));
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn test_try_from_builder() {
+        use crate::builder::*;
+
+        // no PLC, creation fails
+        let res = RawTag::try_from(
+            PathBuilder::default()
+                .protocol(Protocol::EIP)
+                .gateway("127.0.0.1")
+                .plc(PlcKind::ControlLogix)
+                .name("MyTag1")
+                .path("1,0"),
+        );
+        assert!(matches!(res, Err(Status::Err(_))));
+
+        let res = RawTag::try_from(PathBuilder::default());
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_BAD_PARAM))));
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let msg = format!("{:?}", tag);
+        assert!(msg.contains(&format!("id: {}", tag.id())));
+        assert!(msg.contains(r#"path: "make=system&family=library&name=debug&debug=4""#));
+        assert!(msg.contains("PLCTAG_STATUS_OK"));
+    }
+
+    #[test]
+    fn test_operation() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+
+        let op = tag.start_read().unwrap();
+        op.complete(Some(Duration::from_secs(1))).unwrap();
+
+        let op = tag.start_read().unwrap();
+        drop(op);
+        assert!(!tag.status().is_pending());
+        let res = tag.read(100);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_raw_id() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        assert_eq!(tag.as_raw(), tag.id());
+        {
+            let borrowed = unsafe { RawTag::from_raw(tag.as_raw(), false) };
+            assert!(borrowed.read(100).is_ok());
+        }
+        // still alive
+        assert!(tag.read(100).is_ok());
+
+        let tag_id = tag.into_raw();
+
+        // not destroyed when dropped
+        let borrowed = unsafe { RawTag::from_raw(tag_id, false) };
+        assert!(borrowed.read(100).is_ok());
+        drop(borrowed);
+
+        let tag = unsafe { RawTag::from_raw(tag_id, true) };
+        assert!(tag.read(100).is_ok());
+        assert_eq!(tag.id(), tag_id);
+    }
+
+    #[test]
+    fn test_try_clone() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let cloned = tag.try_clone(100).unwrap();
+        assert_ne!(cloned.id(), tag.id());
+        assert!(tag.read(100).is_ok());
+        assert!(cloned.read(100).is_ok());
+        assert_eq!(cloned.get_u32(0).unwrap(), tag.get_u32(0).unwrap());
+
+        let borrowed = unsafe { RawTag::from_raw(tag.id(), false) };
+        let res = borrowed.try_clone(100);
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_UNSUPPORTED))));
+    }
+
+    #[test]
+    fn test_reinitialize() {
+        let mut tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let tag_id = tag.id();
+        tag.reinitialize(100).unwrap();
+        assert_ne!(tag.id(), tag_id);
+        assert!(tag.read(100).is_ok());
+        assert!(tag.get_u32(0).is_ok());
+
+        let mut borrowed = unsafe { RawTag::from_raw(tag.id(), false) };
+        assert!(borrowed.reinitialize(100).is_err());
+        assert_eq!(borrowed.id(), tag.id());
+    }
+
+    #[test]
+    fn test_string() {
+        // a counted string of 12 bytes at byte offset 8, count word first
+        let path = "make=system&family=library&name=debug&debug=4&str_is_defined=1&str_is_counted=1&str_count_word_bytes=4&str_max_capacity=12&str_total_length=16&str_pad_bytes=0&str_is_fixed_length=0&str_is_zero_terminated=0&str_is_byte_swapped=0";
+        let tag = RawTag::new(path, 100).unwrap();
+        assert_eq!(tag.get_string_capacity(8).unwrap(), 12);
+
+        tag.write_string(8, "grüße").unwrap();
+        // length is in bytes, not chars
+        assert_eq!(tag.get_string_length(8).unwrap(), 7);
+        assert_eq!(tag.read_string(8).unwrap(), "grüße");
+
+        // 12 chars but 13 bytes
+        let res = tag.write_string(8, "grüße, welt!");
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE))));
+        assert_eq!(tag.read_string(8).unwrap(), "grüße");
+
+        tag.write_string(8, "").unwrap();
+        assert_eq!(tag.read_string(8).unwrap(), "");
+    }
+
+    #[test]
+    fn test_auto_sync() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        assert_eq!(tag.auto_sync_read_ms().unwrap(), 0);
+        assert_eq!(tag.auto_sync_write_ms().unwrap(), 0);
+
+        tag.set_auto_sync_write_ms(50).unwrap();
+        assert_eq!(tag.get_attr("auto_sync_write_ms", 0).unwrap(), 50);
+        tag.set_auto_sync_write_ms(0).unwrap();
+        assert_eq!(tag.get_attr("auto_sync_write_ms", 0).unwrap(), 0);
+
+        tag.set_auto_sync_read_ms(10).unwrap();
+        assert_eq!(tag.get_attr("auto_sync_read_ms", 0).unwrap(), 10);
+        // the debug level is refreshed in background, never 99
+        tag.set_u32(0, 99).unwrap();
+        thread::sleep(Duration::from_millis(200));
+        assert_ne!(tag.get_u32(0).unwrap(), 99);
+
+        tag.set_auto_sync_read_ms(0).unwrap();
+        assert_eq!(tag.auto_sync_read_ms().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_clear_buffer() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let size = tag.size().unwrap() as usize;
+        assert!(size > 0);
+        tag.set_bytes(0, &vec![0xFF; size]).unwrap();
+
+        tag.clear_buffer().unwrap();
+        let mut buf = vec![0xFF; size];
+        assert_eq!(tag.get_bytes(0, &mut buf).unwrap(), size);
+        assert!(buf.iter().all(|v| *v == 0));
+    }
+
+    #[test]
+    fn test_byte_order() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let value = 1.5_f32; // 0x3FC00000
+        let cases = [
+            (ByteOrder::BigEndian, [0x3F, 0xC0, 0x00, 0x00]),
+            (ByteOrder::LittleEndian, [0x00, 0x00, 0xC0, 0x3F]),
+            (ByteOrder::WordSwapped, [0xC0, 0x3F, 0x00, 0x00]),
+        ];
+        for (order, bytes) in cases {
+            tag.set_bytes(4, &bytes).unwrap();
+            assert_eq!(tag.get_f32_with_order(4, order).unwrap(), value);
+
+            tag.set_bytes(4, &[0; 4]).unwrap();
+            tag.set_f32_with_order(4, value, order).unwrap();
+            let mut buf = [0; 4];
+            tag.get_bytes(4, &mut buf).unwrap();
+            assert_eq!(buf, bytes);
+        }
+
+        let value = -2.5_f64; // 0xC004000000000000
+        tag.set_bytes(4, &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xC0])
+            .unwrap();
+        assert_eq!(
+            tag.get_f64_with_order(4, ByteOrder::LittleEndian).unwrap(),
+            value
+        );
+        tag.set_f64_with_order(4, value, ByteOrder::WordSwapped)
+            .unwrap();
+        let mut buf = [0; 8];
+        tag.get_bytes(4, &mut buf).unwrap();
+        assert_eq!(buf, [0x04, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            tag.get_f64_with_order(4, ByteOrder::WordSwapped).unwrap(),
+            value
+        );
+
+        // ABCD = 0x11223344
+        let value = 0x11223344_u32;
+        let cases = [
+            (ByteOrder::BigEndian, [0x11, 0x22, 0x33, 0x44]),
+            (ByteOrder::LittleEndian, [0x44, 0x33, 0x22, 0x11]),
+            (ByteOrder::WordSwapped, [0x22, 0x11, 0x44, 0x33]),
+        ];
+        for (order, bytes) in cases {
+            tag.set_bytes(4, &bytes).unwrap();
+            assert_eq!(tag.get_value_with_order::<u32>(4, order).unwrap(), value);
+            assert_eq!(
+                tag.get_value_with_order::<i32>(4, order).unwrap(),
+                value as i32
+            );
+
+            tag.set_value_with_order(4, value, order).unwrap();
+            let mut buf = [0; 4];
+            tag.get_bytes(4, &mut buf).unwrap();
+            assert_eq!(buf, bytes);
+        }
+
+        tag.set_bytes(4, &[0x12, 0x34]).unwrap();
+        assert_eq!(
+            tag.get_value_with_order::<u16>(4, ByteOrder::BigEndian)
+                .unwrap(),
+            0x1234
+        );
+        assert_eq!(
+            tag.get_value_with_order::<u16>(4, ByteOrder::WordSwapped)
+                .unwrap(),
+            0x3412
+        );
+    }
+
+    #[test]
+    fn test_lock() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        {
+            let guard = tag.lock().unwrap();
+            guard.set_u8(20, 42).unwrap();
+            assert_eq!(guard.get_u8(20).unwrap(), 42);
+        }
+        // unlocked after the guard drops
+        let guard = tag.lock().unwrap();
+        assert_eq!(guard.get_u8(20).unwrap(), 42);
+    }
+
+    #[cfg(feature = "value")]
+    #[test]
+    fn test_write_if_changed() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_u16(20, 7).unwrap();
+        assert!(!tag.write_if_changed(20, 7_u16, 100).unwrap());
+        assert!(tag.write_if_changed(20, 8_u16, 100).unwrap());
+        assert_eq!(tag.get_u16(20).unwrap(), 8);
+    }
+
+    #[cfg(feature = "value")]
+    #[test]
+    fn test_wait_until() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let poll = Duration::from_millis(10);
+
+        // a read only refreshes the debug level, byte 20 keeps the value; match on the third poll
+        tag.set_u8(20, 42).unwrap();
+        let polls = std::cell::Cell::new(0);
+        let value: u8 = tag
+            .wait_until(
+                20,
+                |v| {
+                    polls.set(polls.get() + 1);
+                    polls.get() == 3 && *v == 42
+                },
+                poll,
+                Duration::from_secs(2),
+            )
+            .unwrap();
+        assert_eq!(value, 42);
+        assert_eq!(polls.get(), 3);
+
+        let res = tag.wait_until(20, |_: &u8| false, poll, Duration::from_millis(50));
+        assert!(matches!(res, Err(status) if status.is_timeout()));
+    }
+
+    #[test]
+    fn test_bits() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        // bits 0-3 = mode, 4-7 = state, in byte 20
+        tag.set_u8(20, 0).unwrap();
+        tag.write_bits(160, 4, 0b1010).unwrap();
+        tag.write_bits(164, 4, 0b0110).unwrap();
+        assert_eq!(tag.get_u8(20).unwrap(), 0x6A);
+        assert_eq!(tag.read_bits(160, 4).unwrap(), 0b1010);
+        assert_eq!(tag.read_bits(164, 4).unwrap(), 0b0110);
+
+        assert!(tag.read_bits(160, 0).is_err());
+        assert!(tag.read_bits(160, 33).is_err());
+        assert!(tag.write_bits(160, 4, 0x10).is_err());
+        let size = tag.size().unwrap();
+        assert!(tag.read_bits(size * 8 - 2, 4).is_err());
+    }
+
+    #[test]
+    fn test_bit() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+
+        tag.set_u8(0, 0).unwrap();
+        tag.set_bit(3, true).unwrap();
+        assert_eq!(tag.get_u8(0).unwrap(), 0b1000);
+        assert!(tag.get_bit(3).unwrap());
+        assert!(!tag.get_bit(2).unwrap());
+
+        tag.set_bit(3, false).unwrap();
+        assert_eq!(tag.get_u8(0).unwrap(), 0);
+
+        let size = tag.size().unwrap();
+        assert!(tag.get_bit(size * 8).is_err());
+    }
+
+    #[test]
+    fn test_status_after_nonblocking_read() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+
+        let res = tag.read(0);
+        assert!(res.is_ok() || res.is_pending());
+        let start = Instant::now();
+        let status = loop {
+            let status = tag.status();
+            if !status.is_pending() || start.elapsed() > Duration::from_secs(1) {
+                break status;
+            }
+            thread::yield_now();
+        };
+        assert!(status.is_ok());
+    }
+
+    #[cfg(feature = "value")]
+    #[test]
+    fn test_read_into() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+
+        let mut value = 0u8;
+        let mut values: Vec<u8> = Vec::with_capacity(4);
+        let ptr = values.as_ptr();
+        for _ in 0..3 {
+            tag.read_into(0, &mut value, 100).unwrap();
+            tag.read_values_into(0, 4, &mut values, 100).unwrap();
+            assert_eq!(values.len(), 4);
+            assert_eq!(values.as_ptr(), ptr);
+        }
+
+        let res = tag.read_values_into::<u32>(28, 1, &mut vec![], 100);
+        assert!(res.is_err());
+        // count * stride overflows, nothing reserved
+        let mut values: Vec<u32> = vec![];
+        let res = tag.read_values_into(0, usize::MAX / 2, &mut values, 100);
+        assert!(matches!(
+            res,
+            Err(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))
+        ));
+        assert_eq!(values.capacity(), 0);
+    }
+
+    #[cfg(feature = "value")]
+    #[test]
+    fn test_values() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+
+        let values: Vec<u8> = tag.read_values(0, 4, 100).unwrap();
+        assert_eq!(values.len(), 4);
+
+        tag.set_values(2, &[1u16, 2, 3]).unwrap();
+        let values: Vec<u16> = tag.get_values(2, 3).unwrap();
+        assert_eq!(values, [1, 2, 3]);
+
+        let res = tag.get_values::<u32>(28, 1);
+        assert!(res.is_err());
+        let res = tag.set_values(24, &[1u32, 2]);
+        assert!(res.is_err());
+        // count * stride overflows
+        let res = tag.get_values::<u32>(0, usize::MAX / 2);
+        assert!(matches!(
+            res,
+            Err(Status::Err(ffi::PLCTAG_ERR_OUT_OF_BOUNDS))
+        ));
+    }
 }
@@ -60,6 +60,58 @@ impl Status {
         }
     }
 
+    /// broad category of the status code
+    #[inline]
+    pub fn category(&self) -> StatusCategory {
+        let rc = match self {
+            Status::Ok => return StatusCategory::Ok,
+            Status::Pending => return StatusCategory::Pending,
+            Status::Err(rc) => *rc,
+        };
+        match rc {
+            ffi::PLCTAG_ERR_TIMEOUT => StatusCategory::Timeout,
+            ffi::PLCTAG_ERR_ABORT => StatusCategory::Aborted,
+            ffi::PLCTAG_ERR_BAD_CONNECTION
+            | ffi::PLCTAG_ERR_BAD_GATEWAY
+            | ffi::PLCTAG_ERR_CLOSE
+            | ffi::PLCTAG_ERR_OPEN
+            | ffi::PLCTAG_ERR_READ
+            | ffi::PLCTAG_ERR_WRITE
+            | ffi::PLCTAG_ERR_WINSOCK => StatusCategory::Connection,
+            ffi::PLCTAG_ERR_BAD_CONFIG
+            | ffi::PLCTAG_ERR_BAD_DEVICE
+            | ffi::PLCTAG_ERR_BAD_PARAM
+            | ffi::PLCTAG_ERR_BAD_DATA
+            | ffi::PLCTAG_ERR_DUPLICATE
+            | ffi::PLCTAG_ERR_NOT_FOUND
+            | ffi::PLCTAG_ERR_NOT_IMPLEMENTED
+            | ffi::PLCTAG_ERR_NO_MATCH
+            | ffi::PLCTAG_ERR_OUT_OF_BOUNDS
+            | ffi::PLCTAG_ERR_TOO_LARGE
+            | ffi::PLCTAG_ERR_TOO_SMALL
+            | ffi::PLCTAG_ERR_UNSUPPORTED => StatusCategory::BadConfig,
+            ffi::PLCTAG_ERR_NOT_ALLOWED => StatusCategory::NotPermitted,
+            ffi::PLCTAG_ERR_BAD_REPLY
+            | ffi::PLCTAG_ERR_BAD_STATUS
+            | ffi::PLCTAG_ERR_BUSY
+            | ffi::PLCTAG_ERR_NO_DATA
+            | ffi::PLCTAG_ERR_NO_RESOURCES
+            | ffi::PLCTAG_ERR_PARTIAL
+            | ffi::PLCTAG_ERR_REMOTE_ERR => StatusCategory::Remote,
+            ffi::PLCTAG_ERR_CREATE
+            | ffi::PLCTAG_ERR_ENCODE
+            | ffi::PLCTAG_ERR_MUTEX_DESTROY
+            | ffi::PLCTAG_ERR_MUTEX_INIT
+            | ffi::PLCTAG_ERR_MUTEX_LOCK
+            | ffi::PLCTAG_ERR_MUTEX_UNLOCK
+            | ffi::PLCTAG_ERR_NO_MEM
+            | ffi::PLCTAG_ERR_NULL_PTR
+            | ffi::PLCTAG_ERR_THREAD_CREATE
+            | ffi::PLCTAG_ERR_THREAD_JOIN => StatusCategory::Internal,
+            _ => StatusCategory::Other,
+        }
+    }
+
     /// into [`Result`]
     #[inline(always)]
     pub fn into_result(self) -> Result<()> {
@@ -84,16 +136,53 @@ impl Status {
     /// ```
     #[inline]
     pub fn decode(&self) -> String {
+        self.message().to_owned()
+    }
+
+    /// message of the status from `plc_tag_decode_error`, without allocation.
+    /// `libplctag` returns static strings, including for codes not known by this crate;
+    /// `"unknown error"` if the message is missing or not valid UTF-8.
+    #[inline]
+    pub fn message(&self) -> &'static str {
+        const UNKNOWN: &str = "unknown error";
         let rc = (*self).into();
 
         unsafe {
             let ptr = ffi::plc_tag_decode_error(rc);
-            let msg = CStr::from_ptr(ptr);
-            msg.to_string_lossy().to_string()
+            if ptr.is_null() {
+                return UNKNOWN;
+            }
+            let msg: &'static CStr = CStr::from_ptr(ptr);
+            msg.to_str().unwrap_or(UNKNOWN)
         }
     }
 }
 
+/// broad categories of [`Status`], see [`Status::category`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StatusCategory {
+    /// PLCTAG_STATUS_OK
+    Ok,
+    /// PLCTAG_STATUS_PENDING
+    Pending,
+    /// operation timed out
+    Timeout,
+    /// operation aborted
+    Aborted,
+    /// failed to connect to or talk with the PLC or gateway
+    Connection,
+    /// bad tag attributes or arguments, e.g. tag not found, out of bounds
+    BadConfig,
+    /// operation not allowed
+    NotPermitted,
+    /// the PLC replied with an error, or is busy
+    Remote,
+    /// internal error of `libplctag`
+    Internal,
+    /// unknown status code
+    Other,
+}
+
 impl From<i32> for Status {
     #[inline(always)]
     fn from(rc: i32) -> Status {
@@ -115,7 +204,37 @@ impl From<Status> for i32 {
 impl fmt::Display for Status {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.decode())
+        f.write_str(self.message())
+    }
+}
+
+/// serialized as the numeric code of `libplctag` plus the decoded message,
+/// only the code is used when deserializing
+#[cfg(feature = "serde")]
+impl serde::Serialize for Status {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("Status", 2)?;
+        s.serialize_field("code", &i32::from(*self))?;
+        s.serialize_field("message", &self.decode())?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Status {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Repr {
+            code: i32,
+        }
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(Status::new(repr.code))
     }
 }
 
@@ -143,4 +262,94 @@ mod tests {
         let msg = status.decode();
         assert_eq!(msg, "PLCTAG_STATUS_PENDING");
     }
+
+    #[test]
+    fn test_status_message() {
+        let status = Status::Err(ffi::PLCTAG_ERR_TIMEOUT);
+        assert_eq!(status.message(), "PLCTAG_ERR_TIMEOUT");
+        assert_eq!(status.to_string(), "PLCTAG_ERR_TIMEOUT");
+
+        // code unknown to libplctag
+        let status = Status::Err(-10000);
+        assert!(!status.message().is_empty());
+        assert_eq!(status.to_string(), status.decode());
+    }
+
+    #[test]
+    fn test_status_predicates() {
+        let status = Status::new(PLCTAG_STATUS_OK);
+        assert!(status.is_ok());
+        assert!(!status.is_pending());
+        assert!(!status.is_err());
+
+        let status = Status::new(PLCTAG_STATUS_PENDING);
+        assert!(!status.is_ok());
+        assert!(status.is_pending());
+        assert!(!status.is_err());
+
+        for rc in [
+            ffi::PLCTAG_ERR_TIMEOUT,
+            ffi::PLCTAG_ERR_NOT_FOUND,
+            ffi::PLCTAG_ERR_BAD_DATA,
+        ] {
+            let status = Status::new(rc);
+            assert!(!status.is_ok());
+            assert!(!status.is_pending());
+            assert!(status.is_err());
+        }
+        assert!(Status::new(ffi::PLCTAG_ERR_TIMEOUT).is_timeout());
+        assert!(!Status::new(ffi::PLCTAG_ERR_NOT_FOUND).is_timeout());
+    }
+
+    #[test]
+    fn test_status_category() {
+        assert_eq!(Status::Ok.category(), StatusCategory::Ok);
+        assert_eq!(Status::Pending.category(), StatusCategory::Pending);
+        let cases = [
+            (ffi::PLCTAG_ERR_TIMEOUT, StatusCategory::Timeout),
+            (ffi::PLCTAG_ERR_ABORT, StatusCategory::Aborted),
+            (ffi::PLCTAG_ERR_BAD_GATEWAY, StatusCategory::Connection),
+            (ffi::PLCTAG_ERR_WINSOCK, StatusCategory::Connection),
+            (ffi::PLCTAG_ERR_BAD_PARAM, StatusCategory::BadConfig),
+            (ffi::PLCTAG_ERR_NOT_FOUND, StatusCategory::BadConfig),
+            (ffi::PLCTAG_ERR_NOT_ALLOWED, StatusCategory::NotPermitted),
+            (ffi::PLCTAG_ERR_REMOTE_ERR, StatusCategory::Remote),
+            (ffi::PLCTAG_ERR_NO_RESOURCES, StatusCategory::Remote),
+            (ffi::PLCTAG_ERR_NO_MEM, StatusCategory::Internal),
+            (-12345, StatusCategory::Other),
+        ];
+        for (rc, category) in cases {
+            assert_eq!(Status::from(rc).category(), category);
+        }
+    }
+
+    #[test]
+    fn test_status_code() {
+        for rc in [
+            PLCTAG_STATUS_OK,
+            PLCTAG_STATUS_PENDING,
+            ffi::PLCTAG_ERR_TIMEOUT,
+            ffi::PLCTAG_ERR_NOT_FOUND,
+            -12345,
+        ] {
+            let status = Status::from(rc);
+            assert_eq!(i32::from(status), rc);
+        }
+        assert!(Status::from(-12345).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_status_serde() {
+        let json = serde_json::to_string(&Status::Pending).unwrap();
+        assert_eq!(json, r#"{"code":1,"message":"PLCTAG_STATUS_PENDING"}"#);
+        let status: Status = serde_json::from_str(&json).unwrap();
+        assert!(status.is_pending());
+
+        let status: Status = serde_json::from_str(r#"{"code":-12345}"#).unwrap();
+        assert_eq!(i32::from(status), -12345);
+        let json = serde_json::to_string(&status).unwrap();
+        let status: Status = serde_json::from_str(&json).unwrap();
+        assert_eq!(i32::from(status), -12345);
+    }
 }
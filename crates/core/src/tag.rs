@@ -0,0 +1,216 @@
+// plctag-rs
+//
+// a rust wrapper of libplctag, with rust style APIs and useful extensions.
+// Copyright: 2022, Joylei <leingliu@gmail.com>
+// License: MIT
+
+use crate::{RawTag, Result, Status};
+use std::{rc::Rc, sync::Arc};
+
+/// invokes `$mac` with getter, setter and type of all numeric values
+macro_rules! with_values {
+    ($mac:ident) => {
+        $mac! {
+            get_i8, set_i8: i8;
+            get_u8, set_u8: u8;
+            get_i16, set_i16: i16;
+            get_u16, set_u16: u16;
+            get_i32, set_i32: i32;
+            get_u32, set_u32: u32;
+            get_i64, set_i64: i64;
+            get_u64, set_u64: u64;
+            get_f32, set_f32: f32;
+            get_f64, set_f64: f64;
+        }
+    };
+}
+#[cfg(feature = "mock")]
+pub(crate) use with_values;
+
+macro_rules! value_decl {
+    ($($get:ident, $set:ident: $type:ty;)*) => {
+        $(
+            #[doc = concat!("get ", stringify!($type), " value")]
+            fn $get(&self, byte_offset: u32) -> Result<$type>;
+
+            #[doc = concat!("set ", stringify!($type), " value")]
+            fn $set(&self, byte_offset: u32, value: $type) -> Result<()>;
+        )*
+    };
+}
+
+macro_rules! raw_value_impl {
+    ($($get:ident, $set:ident: $type:ty;)*) => {
+        $(
+            #[inline(always)]
+            fn $get(&self, byte_offset: u32) -> Result<$type> {
+                RawTag::$get(self, byte_offset)
+            }
+
+            #[inline(always)]
+            fn $set(&self, byte_offset: u32, value: $type) -> Result<()> {
+                RawTag::$set(self, byte_offset, value)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_value_impl {
+    ($($get:ident, $set:ident: $type:ty;)*) => {
+        $(
+            #[inline(always)]
+            fn $get(&self, byte_offset: u32) -> Result<$type> {
+                T::$get(self, byte_offset)
+            }
+
+            #[inline(always)]
+            fn $set(&self, byte_offset: u32, value: $type) -> Result<()> {
+                T::$set(self, byte_offset, value)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_tag_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl<T: Tag + ?Sized> Tag for $ty {
+                #[inline(always)]
+                fn read(&self, timeout: u32) -> Status {
+                    T::read(self, timeout)
+                }
+
+                #[inline(always)]
+                fn write(&self, timeout: u32) -> Status {
+                    T::write(self, timeout)
+                }
+
+                #[inline(always)]
+                fn status(&self) -> Status {
+                    T::status(self)
+                }
+
+                #[inline(always)]
+                fn size(&self) -> Result<u32> {
+                    T::size(self)
+                }
+
+                #[inline(always)]
+                fn get_bytes(&self, byte_offset: u32, buf: &mut [u8]) -> Result<usize> {
+                    T::get_bytes(self, byte_offset, buf)
+                }
+
+                #[inline(always)]
+                fn set_bytes(&self, byte_offset: u32, buf: &[u8]) -> Result<usize> {
+                    T::set_bytes(self, byte_offset, buf)
+                }
+
+                #[inline(always)]
+                fn get_bit(&self, bit_offset: u32) -> Result<bool> {
+                    T::get_bit(self, bit_offset)
+                }
+
+                #[inline(always)]
+                fn set_bit(&self, bit_offset: u32, value: bool) -> Result<()> {
+                    T::set_bit(self, bit_offset, value)
+                }
+
+                with_values!(delegate_value_impl);
+            }
+        )*
+    };
+}
+
+/// common operations of tags, so that code can be generic over [`RawTag`] and other tag backends, i.e. `MockTag`
+///
+/// # Examples
+/// ```rust,no_run
+/// use plctag_core::{RawTag, Result, Tag};
+///
+/// /// increase the counter in any kind of tag
+/// fn increase(tag: impl Tag, offset: u32) -> Result<i32> {
+///     tag.read(100).into_result()?;
+///     let value = tag.get_i32(offset)? + 1;
+///     tag.set_i32(offset, value)?;
+///     tag.write(100).into_result()?;
+///     Ok(value)
+/// }
+///
+/// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyTag1&elem_count=1&elem_size=4";
+/// let tag = RawTag::new(path, 100).unwrap();
+/// let value = increase(&tag, 0).unwrap();
+/// println!("tag value: {}", value);
+/// ```
+pub trait Tag {
+    /// perform read operation, see [`RawTag::read`]
+    fn read(&self, timeout: u32) -> Status;
+
+    /// perform write operation, see [`RawTag::write`]
+    fn write(&self, timeout: u32) -> Status;
+
+    /// poll tag status
+    fn status(&self) -> Status;
+
+    /// tag size in bytes
+    fn size(&self) -> Result<u32>;
+
+    /// get raw bytes, see [`RawTag::get_bytes`]
+    fn get_bytes(&self, byte_offset: u32, buf: &mut [u8]) -> Result<usize>;
+
+    /// set raw bytes, see [`RawTag::set_bytes`]
+    fn set_bytes(&self, byte_offset: u32, buf: &[u8]) -> Result<usize>;
+
+    /// get bit value, see [`RawTag::get_bit`]
+    fn get_bit(&self, bit_offset: u32) -> Result<bool>;
+
+    /// set bit value, see [`RawTag::set_bit`]
+    fn set_bit(&self, bit_offset: u32, value: bool) -> Result<()>;
+
+    with_values!(value_decl);
+}
+
+impl Tag for RawTag {
+    #[inline(always)]
+    fn read(&self, timeout: u32) -> Status {
+        RawTag::read(self, timeout)
+    }
+
+    #[inline(always)]
+    fn write(&self, timeout: u32) -> Status {
+        RawTag::write(self, timeout)
+    }
+
+    #[inline(always)]
+    fn status(&self) -> Status {
+        RawTag::status(self)
+    }
+
+    #[inline(always)]
+    fn size(&self) -> Result<u32> {
+        RawTag::size(self)
+    }
+
+    #[inline(always)]
+    fn get_bytes(&self, byte_offset: u32, buf: &mut [u8]) -> Result<usize> {
+        RawTag::get_bytes(self, byte_offset, buf)
+    }
+
+    #[inline(always)]
+    fn set_bytes(&self, byte_offset: u32, buf: &[u8]) -> Result<usize> {
+        RawTag::set_bytes(self, byte_offset, buf)
+    }
+
+    #[inline(always)]
+    fn get_bit(&self, bit_offset: u32) -> Result<bool> {
+        RawTag::get_bit(self, bit_offset)
+    }
+
+    #[inline(always)]
+    fn set_bit(&self, bit_offset: u32, value: bool) -> Result<()> {
+        RawTag::set_bit(self, bit_offset, value)
+    }
+
+    with_values!(raw_value_impl);
+}
+
+delegate_tag_impl!(&T, Box<T>, Rc<T>, Arc<T>);
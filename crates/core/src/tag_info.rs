@@ -0,0 +1,117 @@
+// plctag-rs
+//
+// a rust wrapper of libplctag, with rust style APIs and useful extensions.
+// Copyright: 2022, Joylei <leingliu@gmail.com>
+// License: MIT
+
+use crate::{ffi, Result, Status};
+
+/// size of the fixed part of an entry in the `@tags` listing
+const ENTRY_HEADER_SIZE: usize = 22;
+
+/// tag metadata returned by the `@tags` listing, see [`RawTag::list_tags`](crate::RawTag::list_tags)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagInfo {
+    /// instance id of the tag
+    pub id: u32,
+    /// CIP type code of the tag
+    pub type_code: u16,
+    /// size of a single element in bytes
+    pub element_length: u16,
+    /// array dimensions, 0 for unused dimensions
+    pub dimensions: [u32; 3],
+    /// tag name
+    pub name: String,
+}
+
+impl TagInfo {
+    /// parse the raw bytes of a `@tags` listing.
+    ///
+    /// each entry is laid out as:
+    /// - u32 instance id
+    /// - u16 type code
+    /// - u16 element length
+    /// - u32 x 3 array dimensions
+    /// - u16 name length, followed by the name bytes
+    pub fn parse_list(buf: &[u8]) -> Result<Vec<TagInfo>> {
+        let mut items = Vec::new();
+        let mut rest = buf;
+        while !rest.is_empty() {
+            if rest.len() < ENTRY_HEADER_SIZE {
+                return Err(Status::Err(ffi::PLCTAG_ERR_BAD_DATA));
+            }
+            let (header, tail) = rest.split_at(ENTRY_HEADER_SIZE);
+            let name_len = u16::from_le_bytes([header[20], header[21]]) as usize;
+            if tail.len() < name_len {
+                return Err(Status::Err(ffi::PLCTAG_ERR_BAD_DATA));
+            }
+            let (name, tail) = tail.split_at(name_len);
+            let name = String::from_utf8(name.to_vec())
+                .map_err(|_| Status::Err(ffi::PLCTAG_ERR_BAD_DATA))?;
+            items.push(TagInfo {
+                id: read_u32(header, 0),
+                type_code: u16::from_le_bytes([header[4], header[5]]),
+                element_length: u16::from_le_bytes([header[6], header[7]]),
+                dimensions: [
+                    read_u32(header, 8),
+                    read_u32(header, 12),
+                    read_u32(header, 16),
+                ],
+                name,
+            });
+            rest = tail;
+        }
+        Ok(items)
+    }
+}
+
+#[inline(always)]
+fn read_u32(buf: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        let buf = [
+            // MyTag1: DINT
+            0x2a, 0x00, 0x00, 0x00, 0xc4, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, b'M', b'y', b'T', b'a', b'g', b'1',
+            // Arr: INT[10,2]
+            0x10, 0x27, 0x00, 0x00, 0xc3, 0x20, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, b'A', b'r', b'r',
+        ];
+        let items = TagInfo::parse_list(&buf).unwrap();
+        assert_eq!(
+            items,
+            [
+                TagInfo {
+                    id: 42,
+                    type_code: 0xc4,
+                    element_length: 4,
+                    dimensions: [0, 0, 0],
+                    name: "MyTag1".to_owned(),
+                },
+                TagInfo {
+                    id: 10000,
+                    type_code: 0x20c3,
+                    element_length: 2,
+                    dimensions: [10, 2, 0],
+                    name: "Arr".to_owned(),
+                },
+            ]
+        );
+
+        assert!(TagInfo::parse_list(&buf[..30]).is_err());
+        assert!(TagInfo::parse_list(&buf[..10]).is_err());
+        assert!(TagInfo::parse_list(&[]).unwrap().is_empty());
+    }
+}
@@ -4,9 +4,9 @@
 // Copyright: 2022, Joylei <leingliu@gmail.com>
 // License: MIT
 
-use crate::{RawTag, Result};
+use crate::{ffi, RawTag, Result, Status};
 use paste::paste;
-use std::{borrow::Cow, marker::PhantomData, rc::Rc, sync::Arc};
+use std::{borrow::Cow, marker::PhantomData, net::Ipv4Addr, ops::Deref, rc::Rc, sync::Arc};
 
 macro_rules! value_impl {
     ($type: ident) => {
@@ -104,27 +104,93 @@ macro_rules! value_impl {
 ///
 /// Note:
 /// Do not perform expensive operations when you derives [`Decode`] or [`Encode`].
+///
+/// `bool` is bit addressed, the offset passed to [`ValueExt::get_value`] or [`ValueExt::set_value`]
+/// is a bit index into the tag buffer rather than a byte offset.
+/// decoding reads a single bit, encoding sets or clears a single bit and leaves the other bits of the byte untouched;
+/// use [`RawTag::get_bool`] or [`RawTag::set_bool`] for byte sized booleans, i.e. `0`/`1` in a whole byte.
+///
+/// containers, i.e. arrays, tuples, `Option`, [`RawTag::get_values`] and [`TypedArrayView`],
+/// always pass byte offsets to their elements, a `bool` element is the first bit of its byte;
+/// encoding it only touches that bit.
 
 pub trait Decode: Sized {
     /// get value at specified byte offset
     fn decode(tag: &RawTag, offset: u32) -> Result<Self>;
 
+    /// size in bytes of the value in the tag buffer, `size_of::<Self>()` by default.
+    /// it is the stride of arrays, tuples, [`RawTag::get_values`] and [`TypedArrayView`],
+    /// override it if the value takes a different size in the tag buffer than in memory.
+    #[inline]
+    fn wire_size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+
     #[doc(hidden)]
     fn decode_in_place(tag: &RawTag, offset: u32, place: &mut Self) -> Result<()> {
         *place = Decode::decode(tag, offset)?;
         Ok(())
     }
+
+    /// get value at specified byte offset, even for bit addressed values
+    #[doc(hidden)]
+    #[inline]
+    fn decode_at_byte(tag: &RawTag, byte_offset: u32) -> Result<Self> {
+        Decode::decode(tag, byte_offset)
+    }
+
+    /// get `N` values laid out every [`Decode::wire_size`] bytes, see the [`Decode`] impl of arrays
+    #[doc(hidden)]
+    #[inline]
+    fn decode_array<const N: usize>(tag: &RawTag, offset: u32) -> Result<[Self; N]> {
+        let stride = Self::wire_size();
+        let items = (0..N)
+            .map(|i| Self::decode_at_byte(tag, offset + (i * stride) as u32))
+            .collect::<Result<Vec<_>>>()?;
+        match items.try_into() {
+            Ok(v) => Ok(v),
+            Err(_) => unreachable!(),
+        }
+    }
 }
 
 /// see [`Decode`]
 pub trait Encode {
     /// set value at specified byte offset
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()>;
+
+    /// size in bytes of the value in the tag buffer, see [`Decode::wire_size`]
+    #[inline]
+    fn wire_size() -> usize
+    where
+        Self: Sized,
+    {
+        std::mem::size_of::<Self>()
+    }
+
+    /// set value at specified byte offset, even for bit addressed values
+    #[doc(hidden)]
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        self.encode(tag, byte_offset)
+    }
+
+    /// set values laid out every [`Encode::wire_size`] bytes, see the [`Decode`] impl of arrays
+    #[doc(hidden)]
+    #[inline]
+    fn encode_slice(values: &[Self], tag: &RawTag, offset: u32) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let stride = Self::wire_size();
+        for (i, v) in values.iter().enumerate() {
+            v.encode_at_byte(tag, offset + (i * stride) as u32)?;
+        }
+        Ok(())
+    }
 }
 
-value_impl!(bool);
 value_impl!(i8);
-value_impl!(u8);
 value_impl!(i16);
 value_impl!(u16);
 value_impl!(i32);
@@ -134,12 +200,90 @@ value_impl!(u64);
 value_impl!(f32);
 value_impl!(f64);
 
+/// `[u8; N]` is copied by a single [`RawTag::get_bytes_unchecked`]
+impl Decode for u8 {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        tag.get_u8(offset)
+    }
+
+    #[inline]
+    fn decode_array<const N: usize>(tag: &RawTag, offset: u32) -> Result<[Self; N]> {
+        let mut buf = [0; N];
+        tag.get_bytes_unchecked(offset, &mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// `[u8; N]` is copied by a single [`RawTag::set_bytes_unchecked`]
+impl Encode for u8 {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        tag.set_u8(offset, *self)
+    }
+
+    #[inline]
+    fn encode_slice(values: &[Self], tag: &RawTag, offset: u32) -> Result<()> {
+        tag.set_bytes_unchecked(offset, values)?;
+        Ok(())
+    }
+}
+
+/// `offset` is a bit index, see [`RawTag::get_bit`]
+impl Decode for bool {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        tag.get_bit(offset)
+    }
+
+    /// a byte in containers
+    #[inline]
+    fn wire_size() -> usize {
+        1
+    }
+
+    #[inline]
+    fn decode_at_byte(tag: &RawTag, byte_offset: u32) -> Result<Self> {
+        tag.get_bit(byte_offset * 8)
+    }
+}
+
+/// `offset` is a bit index, see [`RawTag::set_bit`]
+impl Encode for bool {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        tag.set_bit(offset, *self)
+    }
+
+    /// a byte in containers
+    #[inline]
+    fn wire_size() -> usize {
+        1
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        tag.set_bit(byte_offset * 8, *self)
+    }
+}
+
 impl<T: Decode> Decode for Option<T> {
     #[inline]
     fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
         let v = T::decode(tag, offset)?;
         Ok(Some(v))
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn decode_at_byte(tag: &RawTag, byte_offset: u32) -> Result<Self> {
+        let v = T::decode_at_byte(tag, byte_offset)?;
+        Ok(Some(v))
+    }
 }
 
 impl<T: Encode> Encode for Option<T> {
@@ -150,6 +294,19 @@ impl<T: Encode> Encode for Option<T> {
         }
         Ok(())
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        if let Some(ref v) = self {
+            v.encode_at_byte(tag, byte_offset)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: Encode> Encode for &T {
@@ -157,6 +314,16 @@ impl<T: Encode> Encode for &T {
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
         T::encode(self, tag, offset)
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        T::encode_at_byte(self, tag, byte_offset)
+    }
 }
 
 impl<T: Decode + Clone> Decode for Cow<'_, T> {
@@ -165,6 +332,17 @@ impl<T: Decode + Clone> Decode for Cow<'_, T> {
         let v = T::decode(tag, offset)?;
         Ok(Cow::Owned(v))
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn decode_at_byte(tag: &RawTag, byte_offset: u32) -> Result<Self> {
+        let v = T::decode_at_byte(tag, byte_offset)?;
+        Ok(Cow::Owned(v))
+    }
 }
 
 impl<T: Encode + Clone> Encode for Cow<'_, T> {
@@ -172,6 +350,16 @@ impl<T: Encode + Clone> Encode for Cow<'_, T> {
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
         T::encode(self, tag, offset)
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        T::encode_at_byte(self, tag, byte_offset)
+    }
 }
 
 impl<T: Encode> Encode for Arc<T> {
@@ -179,6 +367,16 @@ impl<T: Encode> Encode for Arc<T> {
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
         T::encode(self, tag, offset)
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        T::encode_at_byte(self, tag, byte_offset)
+    }
 }
 
 impl<T: Encode> Encode for Rc<T> {
@@ -186,6 +384,16 @@ impl<T: Encode> Encode for Rc<T> {
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
         T::encode(self, tag, offset)
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        T::encode_at_byte(self, tag, byte_offset)
+    }
 }
 
 impl<T> Encode for PhantomData<T> {
@@ -207,6 +415,16 @@ impl<T: Encode> Encode for Box<T> {
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
         T::encode(self, tag, offset)
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn encode_at_byte(&self, tag: &RawTag, byte_offset: u32) -> Result<()> {
+        T::encode_at_byte(self, tag, byte_offset)
+    }
 }
 
 impl<T: Decode> Decode for Box<T> {
@@ -215,8 +433,96 @@ impl<T: Decode> Decode for Box<T> {
         let v = T::decode(tag, offset)?;
         Ok(Box::new(v))
     }
+
+    #[inline]
+    fn wire_size() -> usize {
+        T::wire_size()
+    }
+
+    #[inline]
+    fn decode_at_byte(tag: &RawTag, byte_offset: u32) -> Result<Self> {
+        let v = T::decode_at_byte(tag, byte_offset)?;
+        Ok(Box::new(v))
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($name: ident)+) => {
+        /// elements are packed one after another from the byte offset, each takes its [`Decode::wire_size`] bytes;
+        /// a `bool` element takes the first bit of its byte.
+        impl<$($name: Decode),+> Decode for ($($name,)+) {
+            #[inline]
+            fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+                let mut _offset = offset;
+                Ok(($({
+                    let v = $name::decode_at_byte(tag, _offset)?;
+                    _offset += $name::wire_size() as u32;
+                    v
+                },)+))
+            }
+
+            #[inline]
+            fn wire_size() -> usize {
+                0 $(+ $name::wire_size())+
+            }
+        }
+
+        /// see the [`Decode`] impl of tuples
+        impl<$($name: Encode),+> Encode for ($($name,)+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+                let ($($name,)+) = self;
+                let mut _offset = offset;
+                $(
+                    $name.encode_at_byte(tag, _offset)?;
+                    _offset += <$name as Encode>::wire_size() as u32;
+                )+
+                Ok(())
+            }
+
+            #[inline]
+            fn wire_size() -> usize {
+                0 $(+ <$name as Encode>::wire_size())+
+            }
+        }
+    };
+}
+
+tuple_impl!(A B);
+tuple_impl!(A B C);
+tuple_impl!(A B C D);
+tuple_impl!(A B C D E);
+tuple_impl!(A B C D E F);
+
+/// elements are laid out every [`Decode::wire_size`] bytes from the byte offset;
+/// a `bool` element takes the first bit of its byte.
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        T::decode_array(tag, offset)
+    }
+
+    #[inline]
+    fn wire_size() -> usize {
+        N * T::wire_size()
+    }
+}
+
+/// see the [`Decode`] impl of arrays
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        T::encode_slice(self, tag, offset)
+    }
+
+    #[inline]
+    fn wire_size() -> usize {
+        N * T::wire_size()
+    }
 }
 
+/// no fixed size, i.e. it can only be the last element of a tuple
 impl Encode for &[u8] {
     #[inline]
     fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
@@ -225,6 +531,219 @@ impl Encode for &[u8] {
     }
 }
 
+/// 4 bytes in network order
+impl Decode for Ipv4Addr {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        let mut buf = [0; 4];
+        tag.get_bytes_unchecked(offset, &mut buf)?;
+        Ok(Ipv4Addr::from(buf))
+    }
+}
+
+/// 4 bytes in network order
+impl Encode for Ipv4Addr {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        tag.set_bytes_unchecked(offset, &self.octets())?;
+        Ok(())
+    }
+}
+
+/// string with a capacity of `N` bytes, decoded/encoded with the counted string layout,
+/// see [`RawTag::read_string`] and [`RawTag::write_string`].
+///
+/// in containers it takes a 4 bytes count word plus `N` bytes, padded to 4 bytes, like Logix `STRING`.
+///
+/// # Examples
+/// ```rust,no_run
+/// use plctag_core::{FixedString, RawTag, ValueExt};
+/// let timeout = 100;//ms
+/// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyString&elem_count=1";// YOUR TAG DEFINITION
+/// let tag = RawTag::new(path, timeout).unwrap();
+///
+/// let value = FixedString::<82>::new("hello").unwrap();
+/// tag.set_value(0, value).unwrap();
+/// let status = tag.write(timeout);
+/// assert!(status.is_ok());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct FixedString<const N: usize>(String);
+
+impl<const N: usize> FixedString<N> {
+    /// create [`FixedString`], returns a too large error if the string exceeds `N` bytes
+    #[inline]
+    pub fn new(value: impl Into<String>) -> Result<Self> {
+        let value = value.into();
+        if value.len() > N {
+            return Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE));
+        }
+        Ok(Self(value))
+    }
+
+    /// string slice
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// take the inner
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> Decode for FixedString<N> {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        Self::new(tag.read_string(offset)?)
+    }
+
+    #[inline]
+    fn wire_size() -> usize {
+        (4 + N).div_ceil(4) * 4
+    }
+}
+
+impl<const N: usize> Encode for FixedString<N> {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        tag.write_string(offset, &self.0)
+    }
+
+    #[inline]
+    fn wire_size() -> usize {
+        <Self as Decode>::wire_size()
+    }
+}
+
+/// typed view over the tag buffer, elements are laid out every [`Decode::wire_size`] bytes;
+/// a `bool` element is the first bit of its byte. see [`RawTag::array_view`]
+#[derive(Debug)]
+pub struct TypedArrayView<'a, T> {
+    tag: &'a RawTag,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Decode> TypedArrayView<'a, T> {
+    #[inline]
+    pub(crate) fn new(tag: &'a RawTag) -> Result<Self> {
+        let stride = T::wire_size();
+        // no element for zero sized types
+        let len = (tag.size()? as usize).checked_div(stride).unwrap_or(0);
+        Ok(Self {
+            tag,
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// number of elements in the tag buffer
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// no element in the tag buffer?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// get element at index, `None` if out of bounds or failed to decode
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let offset = index * T::wire_size();
+        T::decode_at_byte(self.tag, offset as u32).ok()
+    }
+
+    /// iterate over elements, stops at the first element failed to decode
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len).map_while(move |i| self.get(i))
+    }
+}
+
+/// adapter to decode/encode an enum as a DINT, returns a bad data error for unknown values
+///
+/// # Examples
+/// ```rust,no_run
+/// use plctag_core::{EnumValue, RawTag, ValueExt};
+/// use std::convert::TryFrom;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum State {
+///     Idle = 0,
+///     Running = 1,
+/// }
+/// impl TryFrom<i32> for State {
+///     type Error = i32;
+///     fn try_from(v: i32) -> Result<Self, i32> {
+///         match v {
+///             0 => Ok(State::Idle),
+///             1 => Ok(State::Running),
+///             _ => Err(v),
+///         }
+///     }
+/// }
+/// impl From<State> for i32 {
+///     fn from(v: State) -> i32 {
+///         v as i32
+///     }
+/// }
+///
+/// let timeout = 100;//ms
+/// let path="protocol=ab-eip&plc=controllogix&path=1,0&gateway=192.168.1.120&name=MyState&elem_count=1";// YOUR TAG DEFINITION
+/// let tag = RawTag::new(path, timeout).unwrap();
+/// let status = tag.read(timeout);
+/// assert!(status.is_ok());
+/// let EnumValue(state): EnumValue<State> = tag.get_value(0).unwrap();
+/// println!("state: {:?}", state);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumValue<E>(pub E);
+
+impl<E: TryFrom<i32>> Decode for EnumValue<E> {
+    #[inline]
+    fn decode(tag: &RawTag, offset: u32) -> Result<Self> {
+        let v = tag.get_i32(offset)?;
+        let v = E::try_from(v).map_err(|_| Status::Err(ffi::PLCTAG_ERR_BAD_DATA))?;
+        Ok(EnumValue(v))
+    }
+
+    /// a DINT
+    #[inline]
+    fn wire_size() -> usize {
+        4
+    }
+}
+
+impl<E: Clone + Into<i32>> Encode for EnumValue<E> {
+    #[inline]
+    fn encode(&self, tag: &RawTag, offset: u32) -> Result<()> {
+        tag.set_i32(offset, self.0.clone().into())
+    }
+
+    /// a DINT
+    #[inline]
+    fn wire_size() -> usize {
+        4
+    }
+}
+
 /// generic value getter/setter
 pub trait ValueExt {
     /// get tag value of `T` that derives [`Decode`]
@@ -267,3 +786,277 @@ impl<V: ValueExt> ValueExt for Box<V> {
         (**self).set_value(byte_offset, value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bool() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_value(9, true).unwrap();
+        let value: bool = tag.get_value(9).unwrap();
+        assert!(value);
+        assert_eq!(tag.get_u8(1).unwrap() & 0b10, 0b10);
+
+        tag.set_value(9, false).unwrap();
+        let value: bool = tag.get_value(9).unwrap();
+        assert!(!value);
+        assert_eq!(tag.get_u8(1).unwrap() & 0b10, 0);
+    }
+
+    #[test]
+    fn test_bool_containers() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        // byte offsets, a bool is the first bit of its byte
+        tag.set_bytes(4, &[0; 4]).unwrap();
+        tag.set_value(4, [true, false, true]).unwrap();
+        assert_eq!(tag.get_u32(4).unwrap(), 0x00_01_00_01);
+        let value: [bool; 3] = tag.get_value(4).unwrap();
+        assert_eq!(value, [true, false, true]);
+
+        tag.set_value(7, Some(true)).unwrap();
+        let value: Option<bool> = tag.get_value(7).unwrap();
+        assert_eq!(value, Some(true));
+        assert_eq!(tag.get_u8(7).unwrap(), 1);
+
+        let values: Vec<bool> = tag.get_values(4, 4).unwrap();
+        assert_eq!(values, [true, false, true, true]);
+        tag.set_values(4, &[false, true]).unwrap();
+        assert_eq!(tag.get_u16(4).unwrap(), 0x01_00);
+
+        let view = tag.array_view::<bool>().unwrap();
+        assert_eq!(view.get(5), Some(true));
+        assert_eq!(view.get(6), Some(true));
+        assert_eq!(view.get(8), Some(false));
+    }
+
+    #[test]
+    fn test_array() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_value(4, [1u16, 2, 3]).unwrap();
+        let value: [u16; 3] = tag.get_value(4).unwrap();
+        assert_eq!(value, [1, 2, 3]);
+        assert_eq!(tag.get_u16(8).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_fixed_string() {
+        let value = FixedString::<5>::new("hello").unwrap();
+        assert_eq!(value.as_str(), "hello");
+        assert_eq!(&*value, "hello");
+
+        let res = FixedString::<4>::new("hello");
+        assert!(res.is_err());
+        assert!(FixedString::<4>::default().is_empty());
+    }
+
+    #[test]
+    fn test_fixed_string_tag() {
+        // a counted string of 12 bytes at byte offset 8, count word first
+        let path = "make=system&family=library&name=debug&debug=4&str_is_defined=1&str_is_counted=1&str_count_word_bytes=4&str_max_capacity=12&str_total_length=16&str_pad_bytes=0&str_is_fixed_length=0&str_is_zero_terminated=0&str_is_byte_swapped=0";
+        let tag = RawTag::new(path, 100).unwrap();
+
+        // exact capacity
+        let value = FixedString::<12>::new("hello, world").unwrap();
+        tag.set_value(8, &value).unwrap();
+        assert_eq!(tag.get_u32(8).unwrap(), 12);
+        let res: FixedString<12> = tag.get_value(8).unwrap();
+        assert_eq!(res, value);
+
+        let value = FixedString::<12>::new("grüße").unwrap();
+        tag.set_value(8, &value).unwrap();
+        let res: FixedString<12> = tag.get_value(8).unwrap();
+        assert_eq!(res.as_str(), "grüße");
+
+        // 7 bytes stored, larger than the capacity of the FixedString
+        let res = tag.get_value::<FixedString<6>>(8);
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE))));
+
+        // 13 bytes, larger than the capacity of the tag string
+        let value = FixedString::<16>::new("grüße, welt!").unwrap();
+        let res = tag.set_value(8, value);
+        assert!(matches!(res, Err(Status::Err(ffi::PLCTAG_ERR_TOO_LARGE))));
+    }
+
+    #[test]
+    fn test_tuple() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_u8(12, 0).unwrap();
+        tag.set_value(4, (-7_i32, 2.5_f32, true)).unwrap();
+        let value: (i32, f32, bool) = tag.get_value(4).unwrap();
+        assert_eq!(value, (-7, 2.5, true));
+        assert_eq!(tag.get_i32(4).unwrap(), -7);
+        assert_eq!(tag.get_f32(8).unwrap(), 2.5);
+        assert_eq!(tag.get_u8(12).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_array_view() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_values(4, &[1i32, 2, 3]).unwrap();
+        let view = tag.array_view::<i32>().unwrap();
+        assert_eq!(view.len(), tag.size().unwrap() as usize / 4);
+        assert_eq!(view.get(2), Some(2));
+        assert_eq!(view.get(view.len()), None);
+        let values: Vec<i32> = view.iter().skip(1).take(3).collect();
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum State {
+        Idle = 0,
+        Running = 1,
+        Fault = 2,
+    }
+    impl TryFrom<i32> for State {
+        type Error = i32;
+        fn try_from(v: i32) -> std::result::Result<Self, i32> {
+            match v {
+                0 => Ok(State::Idle),
+                1 => Ok(State::Running),
+                2 => Ok(State::Fault),
+                _ => Err(v),
+            }
+        }
+    }
+    impl From<State> for i32 {
+        fn from(v: State) -> i32 {
+            v as i32
+        }
+    }
+
+    #[test]
+    fn test_enum_value() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        for state in [State::Idle, State::Running, State::Fault] {
+            tag.set_value(4, EnumValue(state)).unwrap();
+            assert_eq!(tag.get_i32(4).unwrap(), state as i32);
+            let value: EnumValue<State> = tag.get_value(4).unwrap();
+            assert_eq!(value.0, state);
+        }
+
+        tag.set_i32(4, 3).unwrap();
+        let res = tag.get_value::<EnumValue<State>>(4);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_wire_size() {
+        assert_eq!(<bool as Decode>::wire_size(), 1);
+        assert_eq!(<Option<u16> as Decode>::wire_size(), 2);
+        assert_eq!(<EnumValue<State> as Decode>::wire_size(), 4);
+        assert_eq!(<FixedString<82> as Decode>::wire_size(), 88);
+        assert_eq!(<(u8, EnumValue<State>) as Decode>::wire_size(), 5);
+        assert_eq!(<[EnumValue<State>; 3] as Decode>::wire_size(), 12);
+
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        // each element is followed by another one
+        tag.set_value(4, (EnumValue(State::Fault), 7_u8)).unwrap();
+        assert_eq!(tag.get_i32(4).unwrap(), 2);
+        assert_eq!(tag.get_u8(8).unwrap(), 7);
+
+        tag.set_value(4, [Some(1_u16), Some(2)]).unwrap();
+        assert_eq!(tag.get_u16(6).unwrap(), 2);
+        let value: (Option<u16>, u16) = tag.get_value(4).unwrap();
+        assert_eq!(value, (Some(1), 2));
+
+        tag.set_value(4, (true, 9_u8)).unwrap();
+        assert_eq!(tag.get_u8(5).unwrap(), 9);
+
+        tag.set_values(4, &[EnumValue(State::Running), EnumValue(State::Fault)])
+            .unwrap();
+        assert_eq!(tag.get_i32(8).unwrap(), 2);
+        let values: Vec<EnumValue<State>> = tag.get_values(4, 2).unwrap();
+        assert_eq!(values, [EnumValue(State::Running), EnumValue(State::Fault)]);
+        let view = tag.array_view::<EnumValue<State>>().unwrap();
+        assert_eq!(view.len(), tag.size().unwrap() as usize / 4);
+        assert_eq!(view.get(2), Some(EnumValue(State::Fault)));
+
+        // a counted string of 12 bytes at byte offset 8, followed by a DINT
+        let path = "make=system&family=library&name=debug&debug=4&str_is_defined=1&str_is_counted=1&str_count_word_bytes=4&str_max_capacity=12&str_total_length=16&str_pad_bytes=0&str_is_fixed_length=0&str_is_zero_terminated=0&str_is_byte_swapped=0";
+        let tag = RawTag::new(path, 100).unwrap();
+        let value = (FixedString::<12>::new("hello").unwrap(), 42_i32);
+        tag.set_value(8, &value).unwrap();
+        assert_eq!(tag.get_i32(24).unwrap(), 42);
+        let res: (FixedString<12>, i32) = tag.get_value(8).unwrap();
+        assert_eq!(res, value);
+    }
+
+    #[test]
+    fn test_64bit() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        tag.set_value(8, -1234567890123_i64).unwrap();
+        let value: i64 = tag.get_value(8).unwrap();
+        assert_eq!(value, -1234567890123);
+
+        tag.set_value(8, u64::MAX - 1).unwrap();
+        let value: u64 = tag.get_value(8).unwrap();
+        assert_eq!(value, u64::MAX - 1);
+        assert_eq!(tag.get_u8(8).unwrap(), 0xfe);
+
+        tag.set_value(8, 3.25_f64).unwrap();
+        let value: f64 = tag.get_value(8).unwrap();
+        assert_eq!(value, 3.25);
+    }
+
+    #[test]
+    fn test_ipv4() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        let addr = Ipv4Addr::new(192, 168, 1, 10);
+        tag.set_value(12, addr).unwrap();
+        let value: Ipv4Addr = tag.get_value(12).unwrap();
+        assert_eq!(value, addr);
+        assert_eq!(tag.get_u8(12).unwrap(), 192);
+        assert_eq!(tag.get_u8(15).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_bytes_array() {
+        let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+        let res = tag.read(100);
+        assert!(res.is_ok());
+
+        let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        tag.set_value(10, bytes).unwrap();
+        let value: [u8; 8] = tag.get_value(10).unwrap();
+        assert_eq!(value, bytes);
+
+        let mut buf = [0; 8];
+        tag.get_bytes(10, &mut buf).unwrap();
+        assert_eq!(buf, bytes);
+
+        // copied as a whole, no partial window at the end of the buffer
+        let res = tag.get_value::<[u8; 8]>(26);
+        assert!(res.is_err());
+        let res = tag.set_value(26, bytes);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,27 @@
+// plctag-rs
+//
+// a rust wrapper of libplctag, with rust style APIs and useful extensions.
+// Copyright: 2022, Joylei <leingliu@gmail.com>
+// License: MIT
+
+// runs in its own process, the debug level is global and other tests change it through `debug=` in tag paths
+
+use plctag_core::{get_debug_level, set_debug_level, DebugLevel};
+
+#[test]
+fn test_set_debug_level() {
+    let origin = get_debug_level();
+    for level in [
+        DebugLevel::None,
+        DebugLevel::Error,
+        DebugLevel::Warn,
+        DebugLevel::Info,
+        DebugLevel::Detail,
+        DebugLevel::Spew,
+    ] {
+        set_debug_level(level);
+        assert_eq!(get_debug_level(), level);
+    }
+    set_debug_level(origin);
+    assert_eq!(get_debug_level(), origin);
+}
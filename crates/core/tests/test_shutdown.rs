@@ -0,0 +1,28 @@
+// plctag-rs
+//
+// a rust wrapper of libplctag, with rust style APIs and useful extensions.
+// Copyright: 2022, Joylei <leingliu@gmail.com>
+// License: MIT
+
+// runs in its own process, shutdown must not race with other tests
+
+use plctag_core::RawTag;
+
+#[test]
+fn test_shutdown() {
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    assert!(tag.status().is_ok());
+    drop(tag);
+
+    plctag_core::shutdown();
+    plctag_core::shutdown();
+
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    assert!(tag.status().is_ok());
+    drop(tag);
+
+    // shutdown again after re-init
+    plctag_core::shutdown();
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    assert!(tag.status().is_ok());
+}
@@ -4,24 +4,32 @@
 // Copyright: 2022, Joylei <leingliu@gmail.com>
 // License: MIT
 
-use crate::shared::{get_crate, get_fields};
+use crate::shared::{get_crate, get_fields, get_struct_size, wire_size};
 use proc_macro2::TokenStream;
 use proc_quote::quote;
 use syn::{DeriveInput, Index};
 
 pub fn expand_tag_derive(input: DeriveInput) -> syn::Result<TokenStream> {
     let plctag = get_crate()?;
+    let size = get_struct_size(&input.attrs)?;
     let items = get_fields(input.data)?;
 
     let gets = items
         .iter()
         .map(|(field_name, ty, i)| {
             let index = Index::from(i.offset as usize);
+            if let Some(bit) = i.bit {
+                let bit = Index::from(bit as usize);
+                return Ok(quote! {
+                    res.#field_name = <#ty as #plctag::Decode>::decode(tag, (offset + #index) * 8 + #bit)?;
+                });
+            }
             Ok(quote! {
-                res.#field_name = #ty::decode(tag, offset + #index)?;
+                res.#field_name = <#ty as #plctag::Decode>::decode_at_byte(tag, offset + #index)?;
             })
         })
         .collect::<syn::Result<TokenStream>>()?;
+    let wire_size = wire_size(&items, &quote!(#plctag::Decode), size);
 
     let st_name = input.ident;
 
@@ -36,6 +44,10 @@ pub fn expand_tag_derive(input: DeriveInput) -> syn::Result<TokenStream> {
                 #gets
                 Ok(res)
             }
+
+            fn wire_size() -> usize {
+                #wire_size
+            }
         }
     })
 }
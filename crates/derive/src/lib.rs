@@ -20,6 +20,13 @@ please use it with [plctag](https://crates.io/crates/plctag)
 
 With this crate, the macros derive `plctag_core::Decode` and `plctag_core::Encode` for you automatically.
 
+Fields are decoded at `offset` bytes from the struct offset; nested derived structs and fixed-size arrays are supported.
+A `bool` field takes the first bit of the byte at `offset`, like in arrays; add `bit` to address another bit,
+i.e. `#[tag(offset=8, bit=3)]` is the fourth bit of byte 8. `bit` may exceed 7 for BOOLs packed in a DINT.
+In arrays, a derived struct takes the bytes up to the end of its last field, see `plctag_core::Decode::wire_size`.
+Logix controllers pad UDTs to a multiple of 4 bytes, add `#[tag(size = <value>)]` to the struct to set its size,
+i.e. `#[tag(size = 4)]` on a struct of a single `i16` field.
+
 ### Examples
 
 ```rust,no_run
@@ -4,9 +4,10 @@
 // Copyright: 2022, Joylei <leingliu@gmail.com>
 // License: MIT
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 use proc_macro_crate::{crate_name, FoundCrate};
-use syn::{Attribute, Data, DataStruct, Fields, Ident, Lit, Meta, NestedMeta, Type};
+use proc_quote::quote;
+use syn::{Attribute, Data, DataStruct, Fields, Ident, Index, Lit, Meta, NestedMeta, Type};
 
 pub fn get_crate() -> syn::Result<Ident> {
     let plctag = match crate_name("plctag").or_else(|_| crate_name("plctag-core")) {
@@ -46,6 +47,12 @@ pub fn get_fields(data: Data) -> syn::Result<Vec<(Ident, Type, TagInfo)>> {
                     return Err(error);
                 }
             };
+            if offset.bit.is_some() && !is_bool(&f.ty) {
+                return Err(syn::Error::new_spanned(
+                    attrs[0],
+                    "tag attribute `bit` is only supported for bool fields",
+                ));
+            }
             let field_name = f.ident.unwrap();
             let ty = f.ty;
             Ok(Some((field_name, ty, offset)))
@@ -63,6 +70,42 @@ pub fn get_fields(data: Data) -> syn::Result<Vec<(Ident, Type, TagInfo)>> {
     Ok(items)
 }
 
+/// struct level `#[tag(size = <value>)]`, the size of the struct in the tag buffer
+pub fn get_struct_size(attrs: &[Attribute]) -> syn::Result<Option<u32>> {
+    let attrs: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("tag"))
+        .collect();
+    let attr = match attrs.len() {
+        0 => return Ok(None),
+        1 => attrs[0],
+        _ => {
+            let mut error = syn::Error::new_spanned(attrs[1], "redundant `tag()` attribute");
+            error.combine(syn::Error::new_spanned(attrs[0], "note: first one here"));
+            return Err(error);
+        }
+    };
+    let nested = match attr.parse_meta()? {
+        Meta::List(list) => list.nested,
+        meta => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "bad usage, please refer to tag attribute",
+            ))
+        }
+    };
+    match nested.iter().collect::<Vec<_>>()[..] {
+        [NestedMeta::Meta(Meta::NameValue(nv))] if nv.path.is_ident("size") => match &nv.lit {
+            Lit::Int(s) => Ok(Some(s.base10_parse()?)),
+            lit => Err(syn::Error::new_spanned(lit, "expected int literal")),
+        },
+        _ => Err(syn::Error::new_spanned(
+            nested,
+            "expected `size = <value>` for structs",
+        )),
+    }
+}
+
 fn get_tag_attr(attr: &Attribute) -> syn::Result<TagInfo> {
     let meta = attr.parse_meta()?;
     //tag()
@@ -77,25 +120,18 @@ fn get_tag_attr(attr: &Attribute) -> syn::Result<TagInfo> {
     };
 
     //extract nested from tag(nested)
-    let nested = match meta_list.nested.len() {
-        1 => &meta_list.nested,
-        _ => {
-            return Err(syn::Error::new_spanned(
-                meta_list.nested,
-                "currently only a single tag attribute is supported",
-            ));
-        }
-    };
+    let nested = &meta_list.nested;
 
     let mut offset = None;
     let mut size = None;
+    let mut bit = None;
     for item in nested {
         let name_value = match item {
             NestedMeta::Meta(Meta::NameValue(nv)) => nv,
             _ => {
                 return Err(syn::Error::new_spanned(
                     nested,
-                    "expected `offset = \"<value>\"` or `size = \"<value>\"`",
+                    "expected `offset = <value>`, `size = <value>` or `bit = <value>`",
                 ))
             }
         };
@@ -123,11 +159,21 @@ fn get_tag_attr(attr: &Attribute) -> syn::Result<TagInfo> {
                 }
                 lit => return Err(syn::Error::new_spanned(lit, "expected int literal")),
             }
+        } else if name_value.path.is_ident("bit") {
+            match &name_value.lit {
+                Lit::Int(s) => {
+                    if bit.is_some() {
+                        return Err(syn::Error::new_spanned(s, "redundant definition for bit"));
+                    }
+                    bit = Some(s.base10_parse()?);
+                }
+                lit => return Err(syn::Error::new_spanned(lit, "expected int literal")),
+            }
         } else {
             // Could also silently ignore the unexpected attribute by returning `Ok(None)`
             return Err(syn::Error::new_spanned(
                 &name_value.path,
-                "unsupported tag attribute, expected `offset` or `size`",
+                "unsupported tag attribute, expected `offset`, `size` or `bit`",
             ));
         }
     }
@@ -142,10 +188,61 @@ fn get_tag_attr(attr: &Attribute) -> syn::Result<TagInfo> {
     Ok(TagInfo {
         offset: offset.unwrap(),
         size,
+        bit,
     })
 }
 
+/// body of `wire_size()`, the struct level `size` if specified, otherwise the end of the last field;
+/// `bound` is the trait of field types
+pub fn wire_size(
+    items: &[(Ident, Type, TagInfo)],
+    bound: &TokenStream,
+    size: Option<u32>,
+) -> TokenStream {
+    if let Some(size) = size {
+        let size = Index::from(size as usize);
+        return quote! { #size };
+    }
+    let ends = field_ends(items, bound);
+    quote! {
+        let mut size: usize = 0;
+        #ends
+        size
+    }
+}
+
+/// the end of each field, for `wire_size()`; `bound` is the trait of field types
+fn field_ends(items: &[(Ident, Type, TagInfo)], bound: &TokenStream) -> TokenStream {
+    items
+        .iter()
+        .map(|(_, ty, i)| match i.bit {
+            Some(bit) => {
+                let end = Index::from((i.offset + bit / 8 + 1) as usize);
+                quote! {
+                    size = size.max(#end);
+                }
+            }
+            None => {
+                let index = Index::from(i.offset as usize);
+                quote! {
+                    size = size.max(#index + <#ty as #bound>::wire_size());
+                }
+            }
+        })
+        .collect()
+}
+
+/// only `bool` fields can be bit addressed
+pub fn is_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p.qself.is_none() && p.path.is_ident("bool"),
+        _ => false,
+    }
+}
+
 pub struct TagInfo {
     pub offset: u32,
     pub size: Option<u32>,
+    /// bit index from the byte offset, for `bool` fields
+    pub bit: Option<u32>,
 }
@@ -4,7 +4,7 @@
 // Copyright: 2022, Joylei <leingliu@gmail.com>
 // License: MIT
 
-use plctag_core::{RawTag, ValueExt};
+use plctag_core::{Decode, Encode, RawTag, ValueExt};
 use plctag_derive::{Decode, Encode};
 
 #[derive(Debug, Default, Decode, Encode)]
@@ -24,3 +24,120 @@ fn test_derive() {
     assert_eq!(udt.a, 4);
     assert_eq!(udt.b, 0);
 }
+
+#[derive(Debug, Default, PartialEq, Decode, Encode)]
+struct Inner {
+    #[tag(offset = 0)]
+    a: i16,
+    #[tag(offset = 2)]
+    b: [u8; 2],
+}
+
+#[derive(Debug, Default, PartialEq, Decode, Encode)]
+struct Outer {
+    #[tag(offset = 0)]
+    a: i16,
+    #[tag(offset = 4)]
+    b: f32,
+    /// bit 1 of byte 8
+    #[tag(offset = 8, bit = 1)]
+    c: bool,
+    /// bit 0 of byte 9
+    #[tag(offset = 9)]
+    g: bool,
+    #[tag(offset = 10)]
+    d: Inner,
+    #[tag(offset = 14)]
+    e: [i16; 3],
+    /// bit 0 of bytes 20 and 21
+    #[tag(offset = 20)]
+    f: [bool; 2],
+}
+
+#[test]
+fn test_derive_round_trip() {
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    let res = tag.read(100);
+    assert!(res.is_ok());
+
+    let udt = Outer {
+        a: -2,
+        b: 1.5,
+        c: true,
+        g: true,
+        d: Inner { a: 7, b: [1, 2] },
+        e: [-1, 0, 1],
+        f: [true, false],
+    };
+    tag.set_value(4, &udt).unwrap();
+    let value: Outer = tag.get_value(4).unwrap();
+    assert_eq!(value, udt);
+
+    assert_eq!(tag.get_i16(4).unwrap(), -2);
+    assert_eq!(tag.get_f32(8).unwrap(), 1.5);
+    assert_eq!(tag.get_u8(12).unwrap(), 0b10);
+    assert_eq!(tag.get_u8(13).unwrap(), 1);
+    assert_eq!(tag.get_i16(14).unwrap(), 7);
+    assert_eq!(tag.get_u8(17).unwrap(), 2);
+    assert_eq!(tag.get_i16(22).unwrap(), 1);
+    assert_eq!(tag.get_u16(24).unwrap(), 1);
+
+    let udt = Outer {
+        c: false,
+        f: [false, true],
+        ..udt
+    };
+    tag.set_value(4, &udt).unwrap();
+    let value: Outer = tag.get_value(4).unwrap();
+    assert_eq!(value, udt);
+    assert_eq!(tag.get_u8(12).unwrap(), 0);
+    assert_eq!(tag.get_u16(24).unwrap(), 0x0100);
+}
+
+#[test]
+fn test_derive_wire_size() {
+    assert_eq!(<Inner as Decode>::wire_size(), 4);
+    assert_eq!(<Outer as Decode>::wire_size(), 22);
+
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    let res = tag.read(100);
+    assert!(res.is_ok());
+
+    let items = [
+        Inner { a: 1, b: [2, 3] },
+        Inner { a: 4, b: [5, 6] },
+    ];
+    tag.set_value(4, &items).unwrap();
+    assert_eq!(tag.get_i16(8).unwrap(), 4);
+    let value: [Inner; 2] = tag.get_value(4).unwrap();
+    assert_eq!(value, items);
+}
+
+/// a UDT of 3 bytes, padded to 4 bytes
+#[derive(Debug, Default, PartialEq, Decode, Encode)]
+#[tag(size = 4)]
+struct Padded {
+    #[tag(offset = 0)]
+    a: i16,
+    #[tag(offset = 2)]
+    b: u8,
+}
+
+#[test]
+fn test_derive_struct_size() {
+    assert_eq!(<Padded as Decode>::wire_size(), 4);
+    assert_eq!(<Padded as Encode>::wire_size(), 4);
+
+    let tag = RawTag::new("make=system&family=library&name=debug&debug=4", 100).unwrap();
+    let res = tag.read(100);
+    assert!(res.is_ok());
+
+    let items = [Padded { a: 1, b: 2 }, Padded { a: 3, b: 4 }];
+    tag.set_value(4, &items).unwrap();
+    assert_eq!(tag.get_i16(8).unwrap(), 3);
+    assert_eq!(tag.get_u8(10).unwrap(), 4);
+    let value: [Padded; 2] = tag.get_value(4).unwrap();
+    assert_eq!(value, items);
+    let values: Vec<Padded> = tag.get_values(4, 2).unwrap();
+    assert_eq!(values, items);
+}
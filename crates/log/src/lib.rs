@@ -55,29 +55,12 @@ extern crate plctag_core;
 #[macro_use]
 extern crate log;
 
-pub use plctag_core::builder::DebugLevel;
+pub use plctag_core::{get_debug_level, set_debug_level, DebugLevel};
 
 use plctag_core::ffi;
-use std::ffi::{CStr, CString};
+use std::ffi::CStr;
 use std::os::raw::c_char;
 
-/// set debug level of `libplctag`
-///
-/// #Note
-/// `libplctag` will print logs to stdout even if you register your own logger by `plc::register_logger`
-#[inline]
-pub fn set_debug_level(debug: DebugLevel) {
-    let level = debug as u8;
-    unsafe { ffi::plc_tag_set_debug_level(level as i32) };
-}
-
-/// retrieve debug level
-#[inline(always)]
-pub fn get_debug_level() -> DebugLevel {
-    let v = get_int_attr("debug");
-    (v as u8).into()
-}
-
 /// register a custom logger to receive inner message of `libplctag`
 ///
 /// # Note
@@ -85,12 +68,6 @@ pub fn get_debug_level() -> DebugLevel {
 pub use ffi::plc_tag_register_logger as register_logger;
 pub use ffi::plc_tag_unregister_logger as unregister_logger;
 
-#[inline(always)]
-fn get_int_attr(attr: &str) -> i32 {
-    let attr = CString::new(attr).unwrap();
-    unsafe { ffi::plc_tag_get_int_attribute(0, attr.as_ptr(), 0) }
-}
-
 #[doc(hidden)]
 unsafe extern "C" fn log_route(_tag_id: i32, level: i32, message: *const c_char) {
     let msg = CStr::from_ptr(message).to_string_lossy();
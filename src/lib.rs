@@ -183,3 +183,22 @@ pub use plctag_async as futures;
 
 #[cfg(feature = "async")]
 pub use plctag_async::AsyncTag;
+
+/// commonly used types and traits, `use plctag::prelude::*;`
+pub mod prelude {
+    /// tag path builder
+    #[cfg(feature = "builder")]
+    pub use crate::builder::PathBuilder;
+    /// async tag
+    #[cfg(feature = "async")]
+    pub use crate::AsyncTag;
+    /// tag handle
+    pub use crate::RawTag;
+    /// status of tag operations
+    pub use crate::Status;
+    /// common operations of tags
+    pub use crate::Tag;
+    /// traits to decode/encode values of tag, and the derive macros if feature `derive` enabled
+    #[cfg(any(feature = "value", feature = "derive"))]
+    pub use crate::{Decode, Encode, ValueExt};
+}